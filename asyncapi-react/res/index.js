@@ -0,0 +1,7 @@
+/*
+ * Placeholder for the AsyncAPI React component standalone bundle.
+ *
+ * Run `./update.sh <version>` in the crate directory to download the actual
+ * file from the @asyncapi/react-component npm package.
+ */
+console.error("The AsyncAPI React component has not been bundled; run update.sh to download it.");
@@ -0,0 +1,772 @@
+//! This crate implements necessary boilerplate code to serve the [AsyncAPI
+//! React component] via web server. It provides a simple API to configure the
+//! component and serve it via a web server. The crate is deliberately kept
+//! simple and does not implement any web server specific code. It is up to
+//! the user to implement the web server specific code for the web framework of
+//! choice.
+//!
+//! It does not download the AsyncAPI React component from the internet, but
+//! rather includes the necessary static files in the crate. This reduces the
+//! number of build dependencies and makes it easy to use the crate offline.
+//!
+//! The page generated by this crate renders AsyncAPI documents, describing
+//! event-driven and message based APIs. It implements the [`OpenApiGui`]
+//! trait nevertheless, so it can be served by the same code as the OpenAPI
+//! GUIs. OpenAPI spec URLs are ignored, though, as they can't be rendered as
+//! AsyncAPI documents; the document URL is set with [`Config::schema_url`].
+//!
+//! # Attribution
+//!
+//! [AsyncAPI React component] included in this crate is licensed under
+//! Apache 2.0.
+//!
+//! [AsyncAPI React component]: https://github.com/asyncapi/asyncapi-react
+
+#![warn(missing_docs, rustdoc::missing_crate_level_docs)]
+
+use std::borrow::Cow;
+use std::error::Error;
+
+use openapi_gui_core::{FilePaths, GuiFile, OpenApiGui, SpecUrl, StaticFile, escape_html};
+use serde::Serialize;
+
+/// Entry point for serving the AsyncAPI React component and api docs in
+/// application. It provides builder style chainable configuration methods for
+/// configuring the AsyncAPI document url and the look of the page.
+///
+/// # Examples
+///
+/// ```
+/// # use asyncapi_react_redist::AsyncApiReact;
+/// let mut asyncapi = AsyncApiReact::new();
+/// asyncapi.config().schema_url("/api-docs/asyncapi.json");
+/// let static_files = AsyncApiReact::static_files(); // static files that are needed to be served
+/// let html = asyncapi.serve()?;
+/// # Ok::<(), Box<dyn std::error::Error + Send + Sync>>(())
+/// ```
+#[non_exhaustive]
+#[derive(Debug, Clone)]
+pub struct AsyncApiReact {
+    title: Cow<'static, str>,
+    config: Config,
+    file_paths: FilePaths<AsyncApiReactStaticFile>,
+}
+
+impl Default for AsyncApiReact {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl AsyncApiReact {
+    /// Create a new [`AsyncApiReact`] with the default configuration.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use asyncapi_react_redist::AsyncApiReact;
+    /// let asyncapi = AsyncApiReact::new();
+    /// ```
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            title: Cow::Borrowed("AsyncAPI"),
+            config: Config::new(),
+            file_paths: FilePaths::new(),
+        }
+    }
+
+    /// Return a mutable reference to the config, allowing to modify it.
+    ///
+    /// This is useful for setting up the AsyncAPI React component with custom
+    /// settings, but also for setting up the URL of the AsyncAPI document.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use asyncapi_react_redist::AsyncApiReact;
+    /// let mut asyncapi = AsyncApiReact::new();
+    /// asyncapi
+    ///     .config()
+    ///     .schema_url("/api-docs/asyncapi.json")
+    ///     .show_sidebar(true);
+    /// ```
+    pub fn config(&mut self) -> &mut Config {
+        &mut self.config
+    }
+
+    /// Sets the title for the AsyncAPI page.
+    ///
+    /// The title will be displayed in the browser tab.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use asyncapi_react_redist::AsyncApiReact;
+    /// let mut asyncapi = AsyncApiReact::new();
+    /// asyncapi.title("My Events Documentation");
+    /// ```
+    pub fn title(&mut self, title: impl Into<Cow<'static, str>>) -> &mut Self {
+        self.title = title.into();
+        self
+    }
+
+    /// Returns a reference to all static files required by the AsyncAPI React
+    /// component.
+    ///
+    /// Each file is paired with its corresponding [`AsyncApiReactStaticFile`]
+    /// enum variant for identification.
+    ///
+    /// # Returns
+    ///
+    /// A static slice of tuples containing the file identifier and its raw
+    /// content.
+    #[must_use]
+    pub fn static_files() -> &'static [(AsyncApiReactStaticFile, &'static [u8])] {
+        &[
+            (
+                AsyncApiReactStaticFile::Js,
+                include_bytes!("../res/index.js"),
+            ),
+            (
+                AsyncApiReactStaticFile::Css,
+                include_bytes!("../res/default.min.css"),
+            ),
+        ]
+    }
+
+    /// Overrides the path for a specific static file.
+    ///
+    /// This method allows customizing the URL paths where static files are
+    /// served from. This is useful when integrating with web frameworks
+    /// that have specific routing requirements or when serving files from a
+    /// CDN or different location.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use asyncapi_react_redist::{AsyncApiReact, AsyncApiReactStaticFile};
+    /// let mut asyncapi = AsyncApiReact::new();
+    /// asyncapi.override_file_path(
+    ///     AsyncApiReactStaticFile::Css,
+    ///     "/assets/asyncapi.css".to_string(),
+    /// );
+    /// ```
+    pub fn override_file_path(&mut self, static_file: AsyncApiReactStaticFile, path: String) {
+        self.file_paths.set(static_file, path);
+    }
+
+    /// Looks up the file that should be served under the given path.
+    ///
+    /// The path is relative to the location the AsyncAPI page is exposed at.
+    /// An empty path (or `index.html`) resolves to the HTML page generated by
+    /// [`AsyncApiReact::serve`], while the remaining paths are matched against
+    /// the (possibly overridden) relative paths of the static files. Leading
+    /// slashes are ignored.
+    ///
+    /// Static files whose path has been overridden with an absolute path or a
+    /// URL are not served, as the generated page does not reference them
+    /// relative to the AsyncAPI page location.
+    ///
+    /// # Returns
+    ///
+    /// The file to be served, or `None` if there is no file under given path.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the AsyncAPI React component config fails to be
+    /// serialized.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use asyncapi_react_redist::AsyncApiReact;
+    /// let mut asyncapi = AsyncApiReact::new();
+    /// asyncapi.config().schema_url("/api-docs/asyncapi.json");
+    ///
+    /// let index = asyncapi.file("/")?.expect("index should be present");
+    /// assert_eq!(index.content_type, "text/html; charset=utf-8");
+    ///
+    /// let css = asyncapi
+    ///     .file("/default.min.css")?
+    ///     .expect("CSS should be present");
+    /// assert_eq!(css.content_type, "text/css; charset=utf-8");
+    ///
+    /// assert!(asyncapi.file("/does-not-exist.js")?.is_none());
+    /// # Ok::<(), Box<dyn std::error::Error + Send + Sync>>(())
+    /// ```
+    pub fn file(
+        &self,
+        path: &str,
+    ) -> Result<Option<AsyncApiReactFile<'static>>, Box<dyn Error + Send + Sync>> {
+        OpenApiGui::file(self, path)
+    }
+
+    /// Generates the HTML for the AsyncAPI page.
+    ///
+    /// This method creates a complete HTML document that references the
+    /// AsyncAPI React component bundle and stylesheet, and renders the
+    /// component with the settings specified in the [`Config`] object.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the AsyncAPI React component config fails to be
+    /// serialized.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use asyncapi_react_redist::AsyncApiReact;
+    /// let mut asyncapi = AsyncApiReact::new();
+    /// asyncapi.config().schema_url("/api-docs/asyncapi.json");
+    /// let html = asyncapi.serve()?;
+    /// # Ok::<(), Box<dyn std::error::Error + Send + Sync>>(())
+    /// ```
+    pub fn serve(&self) -> Result<String, Box<dyn Error + Send + Sync>> {
+        let title = escape_html(&self.title);
+        let js_path = escape_html(self.file_paths.get(AsyncApiReactStaticFile::Js));
+        let css_path = escape_html(self.file_paths.get(AsyncApiReactStaticFile::Css));
+        // the props must not end the script
+        let props = serde_json::to_string(&Props {
+            schema: Schema {
+                url: &self.config.schema_url,
+                options: SchemaOptions {
+                    method: "GET",
+                    mode: "cors",
+                },
+            },
+            config: &self.config,
+        })?
+        .replace("</", r"<\/");
+
+        Ok(format!(
+            r#"<!DOCTYPE html>
+<html lang="en">
+<head>
+    <meta charset="UTF-8">
+    <meta name="viewport" content="width=device-width, initial-scale=1">
+    <title>{title}</title>
+    <link rel="stylesheet" type="text/css" href="{css_path}">
+</head>
+<body>
+<div id="asyncapi"></div>
+<script src="{js_path}" charset="UTF-8"></script>
+<script>
+    AsyncApiStandalone.render({props}, document.getElementById("asyncapi"));
+</script>
+</body>
+</html>
+"#
+        ))
+    }
+}
+
+/// The props the standalone AsyncAPI React component is rendered with.
+#[derive(Debug, Serialize)]
+struct Props<'a> {
+    schema: Schema<'a>,
+    config: &'a Config,
+}
+
+#[derive(Debug, Serialize)]
+struct Schema<'a> {
+    url: &'a str,
+    options: SchemaOptions,
+}
+
+/// Options of the `fetch` call the AsyncAPI document is downloaded with.
+#[derive(Debug, Serialize)]
+struct SchemaOptions {
+    method: &'static str,
+    mode: &'static str,
+}
+
+/// Represents the static files required by the AsyncAPI React component.
+#[non_exhaustive]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum AsyncApiReactStaticFile {
+    /// The standalone AsyncAPI React component JavaScript bundle. It includes
+    /// React itself.
+    Js,
+    /// The default AsyncAPI React component stylesheet.
+    Css,
+}
+
+impl AsyncApiReactStaticFile {
+    /// Returns a slice containing all available AsyncAPI React component
+    /// static files.
+    #[must_use]
+    pub fn all() -> &'static [Self] {
+        &[AsyncApiReactStaticFile::Js, AsyncApiReactStaticFile::Css]
+    }
+
+    /// Returns the filename for a specific static file.
+    #[must_use]
+    pub fn file_name(&self) -> &'static str {
+        match self {
+            AsyncApiReactStaticFile::Js => "index.js",
+            AsyncApiReactStaticFile::Css => "default.min.css",
+        }
+    }
+
+    /// Returns the static file with given filename, if there is one.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use asyncapi_react_redist::AsyncApiReactStaticFile;
+    /// assert_eq!(
+    ///     AsyncApiReactStaticFile::from_file_name("default.min.css"),
+    ///     Some(AsyncApiReactStaticFile::Css)
+    /// );
+    /// assert_eq!(AsyncApiReactStaticFile::from_file_name("index.html"), None);
+    /// ```
+    #[must_use]
+    pub fn from_file_name(file_name: &str) -> Option<Self> {
+        Self::all()
+            .iter()
+            .copied()
+            .find(|file| file.file_name() == file_name)
+    }
+
+    /// Returns the content type of a specific static file, suitable for use in
+    /// the `Content-Type` header.
+    #[must_use]
+    pub fn content_type(&self) -> &'static str {
+        match self {
+            AsyncApiReactStaticFile::Js => "text/javascript; charset=utf-8",
+            AsyncApiReactStaticFile::Css => "text/css; charset=utf-8",
+        }
+    }
+
+    /// Returns the raw content of a specific static file.
+    ///
+    /// This is the same content as returned by
+    /// [`AsyncApiReact::static_files`].
+    #[must_use]
+    #[expect(clippy::missing_panics_doc)]
+    pub fn bytes(&self) -> &'static [u8] {
+        AsyncApiReact::static_files()
+            .iter()
+            .find(|(file, _)| file == self)
+            .map(|(_, bytes)| *bytes)
+            .expect("all files should be present")
+    }
+}
+
+impl StaticFile for AsyncApiReactStaticFile {
+    fn all() -> &'static [Self] {
+        Self::all()
+    }
+
+    fn file_name(&self) -> &'static str {
+        self.file_name()
+    }
+
+    fn content_type(&self) -> &'static str {
+        self.content_type()
+    }
+
+    fn bytes(&self) -> &'static [u8] {
+        self.bytes()
+    }
+}
+
+impl OpenApiGui for AsyncApiReact {
+    type StaticFile = AsyncApiReactStaticFile;
+
+    fn set_title(&mut self, title: Cow<'static, str>) {
+        self.title = title;
+    }
+
+    /// Does nothing, as the URLs point to OpenAPI specs, which the AsyncAPI
+    /// React component can't render. Use [`Config::schema_url`] to set the
+    /// URL of the AsyncAPI document instead.
+    fn set_spec_urls(&mut self, _urls: Vec<SpecUrl>) {}
+
+    fn file_paths(&self) -> &FilePaths<AsyncApiReactStaticFile> {
+        &self.file_paths
+    }
+
+    fn file_paths_mut(&mut self) -> &mut FilePaths<AsyncApiReactStaticFile> {
+        &mut self.file_paths
+    }
+
+    fn render_index(&self) -> Result<String, Box<dyn Error + Send + Sync>> {
+        self.serve()
+    }
+}
+
+/// Represents servable file of the AsyncAPI React component. This is returned
+/// by [`AsyncApiReact::file`] to serve the component files via web server.
+pub type AsyncApiReactFile<'a> = GuiFile<'a>;
+
+/// How the servers are grouped in the sidebar.
+#[non_exhaustive]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Serialize)]
+pub enum ServerGrouping {
+    /// Don't group the servers.
+    #[serde(rename = "byDefault")]
+    None,
+    /// Group the servers by the tags of the document.
+    #[serde(rename = "bySpecTags")]
+    SpecTags,
+    /// Group the servers by their own tags.
+    #[serde(rename = "byServersTags")]
+    ServerTags,
+}
+
+/// How the operations are grouped in the sidebar.
+#[non_exhaustive]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Serialize)]
+pub enum OperationGrouping {
+    /// Don't group the operations.
+    #[serde(rename = "byDefault")]
+    None,
+    /// Group the operations by the tags of the document.
+    #[serde(rename = "bySpecTags")]
+    SpecTags,
+    /// Group the operations by their own tags.
+    #[serde(rename = "byOperationsTags")]
+    OperationTags,
+}
+
+/// Object used to alter the AsyncAPI React component settings.
+///
+/// Only the options that were set explicitly are passed to the component, so
+/// its own defaults apply to everything else.
+///
+/// # Examples
+///
+/// ```
+/// # use asyncapi_react_redist::{Config, OperationGrouping};
+/// let mut config = Config::new();
+/// config
+///     .schema_url("/api-docs/asyncapi.json")
+///     .show_servers(false)
+///     .expand_message_examples(true)
+///     .sidebar_operations(OperationGrouping::SpecTags);
+/// ```
+#[non_exhaustive]
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Config {
+    /// URL of the AsyncAPI document. This is passed to the component
+    /// separately from the config.
+    #[serde(skip)]
+    schema_url: String,
+
+    /// Identifier of the document, used as a prefix of the element IDs.
+    #[serde(rename = "schemaID", skip_serializing_if = "Option::is_none")]
+    schema_id: Option<String>,
+
+    /// Sections of the document to show.
+    #[serde(skip_serializing_if = "Show::is_empty")]
+    show: Show,
+
+    /// Elements expanded by default.
+    #[serde(skip_serializing_if = "Expand::is_empty")]
+    expand: Expand,
+
+    /// Grouping of the sidebar entries.
+    #[serde(skip_serializing_if = "Sidebar::is_empty")]
+    sidebar: Sidebar,
+
+    /// Label of the AsyncAPI 2 `publish` operations.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    publish_label: Option<String>,
+
+    /// Label of the AsyncAPI 2 `subscribe` operations.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    subscribe_label: Option<String>,
+
+    /// Label of the AsyncAPI 3 `send` operations.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    send_label: Option<String>,
+
+    /// Label of the AsyncAPI 3 `receive` operations.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    receive_label: Option<String>,
+}
+
+#[derive(Debug, Default, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct Show {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    sidebar: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    info: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    servers: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    operations: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    messages: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    schemas: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    errors: Option<bool>,
+}
+
+impl Show {
+    fn is_empty(&self) -> bool {
+        [
+            self.sidebar,
+            self.info,
+            self.servers,
+            self.operations,
+            self.messages,
+            self.schemas,
+            self.errors,
+        ]
+        .iter()
+        .all(Option::is_none)
+    }
+}
+
+#[derive(Debug, Default, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct Expand {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    message_examples: Option<bool>,
+}
+
+impl Expand {
+    fn is_empty(&self) -> bool {
+        self.message_examples.is_none()
+    }
+}
+
+#[derive(Debug, Default, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct Sidebar {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    show_servers: Option<ServerGrouping>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    show_operations: Option<OperationGrouping>,
+}
+
+impl Sidebar {
+    fn is_empty(&self) -> bool {
+        self.show_servers.is_none() && self.show_operations.is_none()
+    }
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            schema_url: DEFAULT_SCHEMA_URL.to_string(),
+            schema_id: None,
+            show: Show::default(),
+            expand: Expand::default(),
+            sidebar: Sidebar::default(),
+            publish_label: None,
+            subscribe_label: None,
+            send_label: None,
+            receive_label: None,
+        }
+    }
+}
+
+const DEFAULT_SCHEMA_URL: &str = "/asyncapi.json";
+
+impl Config {
+    /// Constructs a new [`Config`] with the AsyncAPI React component's default
+    /// settings and the document URL set to `/asyncapi.json`.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the URL of the AsyncAPI document to be rendered.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use asyncapi_react_redist::Config;
+    /// let mut config = Config::new();
+    /// config.schema_url("/api-docs/asyncapi.json");
+    /// ```
+    pub fn schema_url<S: Into<String>>(&mut self, schema_url: S) -> &mut Self {
+        self.schema_url = schema_url.into();
+        self
+    }
+
+    /// Sets the identifier of the document, used as a prefix of the IDs of
+    /// the rendered elements.
+    pub fn schema_id<S: Into<String>>(&mut self, schema_id: S) -> &mut Self {
+        self.schema_id = Some(schema_id.into());
+        self
+    }
+
+    /// Sets whether the sidebar is shown. Default is `false`.
+    pub fn show_sidebar(&mut self, show_sidebar: bool) -> &mut Self {
+        self.show.sidebar = Some(show_sidebar);
+        self
+    }
+
+    /// Sets whether the info section is shown. Default is `true`.
+    pub fn show_info(&mut self, show_info: bool) -> &mut Self {
+        self.show.info = Some(show_info);
+        self
+    }
+
+    /// Sets whether the servers section is shown. Default is `true`.
+    pub fn show_servers(&mut self, show_servers: bool) -> &mut Self {
+        self.show.servers = Some(show_servers);
+        self
+    }
+
+    /// Sets whether the operations section is shown. Default is `true`.
+    pub fn show_operations(&mut self, show_operations: bool) -> &mut Self {
+        self.show.operations = Some(show_operations);
+        self
+    }
+
+    /// Sets whether the messages section is shown. Default is `true`.
+    pub fn show_messages(&mut self, show_messages: bool) -> &mut Self {
+        self.show.messages = Some(show_messages);
+        self
+    }
+
+    /// Sets whether the schemas section is shown. Default is `true`.
+    pub fn show_schemas(&mut self, show_schemas: bool) -> &mut Self {
+        self.show.schemas = Some(show_schemas);
+        self
+    }
+
+    /// Sets whether the errors in the document are shown. Default is `true`.
+    pub fn show_errors(&mut self, show_errors: bool) -> &mut Self {
+        self.show.errors = Some(show_errors);
+        self
+    }
+
+    /// Sets whether the message examples are expanded by default. Default is
+    /// `false`.
+    pub fn expand_message_examples(&mut self, expand_message_examples: bool) -> &mut Self {
+        self.expand.message_examples = Some(expand_message_examples);
+        self
+    }
+
+    /// Sets how the servers are grouped in the sidebar. Default is
+    /// [`ServerGrouping::None`].
+    pub fn sidebar_servers(&mut self, grouping: ServerGrouping) -> &mut Self {
+        self.sidebar.show_servers = Some(grouping);
+        self
+    }
+
+    /// Sets how the operations are grouped in the sidebar. Default is
+    /// [`OperationGrouping::None`].
+    pub fn sidebar_operations(&mut self, grouping: OperationGrouping) -> &mut Self {
+        self.sidebar.show_operations = Some(grouping);
+        self
+    }
+
+    /// Sets the label of the AsyncAPI 2 `publish` operations. Default is
+    /// `"PUB"`.
+    pub fn publish_label<S: Into<String>>(&mut self, publish_label: S) -> &mut Self {
+        self.publish_label = Some(publish_label.into());
+        self
+    }
+
+    /// Sets the label of the AsyncAPI 2 `subscribe` operations. Default is
+    /// `"SUB"`.
+    pub fn subscribe_label<S: Into<String>>(&mut self, subscribe_label: S) -> &mut Self {
+        self.subscribe_label = Some(subscribe_label.into());
+        self
+    }
+
+    /// Sets the label of the AsyncAPI 3 `send` operations. Default is
+    /// `"SEND"`.
+    pub fn send_label<S: Into<String>>(&mut self, send_label: S) -> &mut Self {
+        self.send_label = Some(send_label.into());
+        self
+    }
+
+    /// Sets the label of the AsyncAPI 3 `receive` operations. Default is
+    /// `"RECEIVE"`.
+    pub fn receive_label<S: Into<String>>(&mut self, receive_label: S) -> &mut Self {
+        self.receive_label = Some(receive_label.into());
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serve_default() {
+        let html = AsyncApiReact::new().serve().unwrap();
+
+        assert!(html.contains("<title>AsyncAPI</title>"));
+        assert!(
+            html.contains(r#"<link rel="stylesheet" type="text/css" href="./default.min.css">"#)
+        );
+        assert!(html.contains(r#"<script src="./index.js" charset="UTF-8"></script>"#));
+        assert!(html.contains(
+            r#"AsyncApiStandalone.render({"schema":{"url":"/asyncapi.json","options":{"method":"GET","mode":"cors"}},"config":{}}, document.getElementById("asyncapi"));"#
+        ));
+    }
+
+    #[test]
+    fn serve_escapes_script_end() {
+        let mut asyncapi = AsyncApiReact::new();
+        asyncapi
+            .title("</title>")
+            .config()
+            .schema_url("/asyncapi.json?</script>")
+            .publish_label("</script>");
+
+        let html = asyncapi.serve().unwrap();
+
+        assert_eq!(html.matches("</script>").count(), 2);
+        assert!(html.contains("<title>&lt;/title&gt;</title>"));
+        assert!(html.contains(r#""url":"/asyncapi.json?<\/script>""#));
+    }
+
+    #[test]
+    fn config_serialization() {
+        let mut config = Config::new();
+        config
+            .schema_url("/events.json")
+            .schema_id("events")
+            .show_sidebar(true)
+            .show_errors(false)
+            .expand_message_examples(true)
+            .sidebar_servers(ServerGrouping::ServerTags)
+            .sidebar_operations(OperationGrouping::None)
+            .send_label("OUT");
+
+        assert_eq!(
+            serde_json::to_value(&config).unwrap(),
+            serde_json::json!({
+                "schemaID": "events",
+                "show": {"sidebar": true, "errors": false},
+                "expand": {"messageExamples": true},
+                "sidebar": {"showServers": "byServersTags", "showOperations": "byDefault"},
+                "sendLabel": "OUT",
+            })
+        );
+    }
+
+    #[test]
+    fn file_lookup() {
+        let mut asyncapi = AsyncApiReact::new();
+        asyncapi.config().schema_url("/events.json");
+        asyncapi.set_spec_urls(vec![SpecUrl::new("/openapi.json")]);
+
+        let index = asyncapi.file("/").unwrap().unwrap();
+        assert!(String::from_utf8_lossy(&index.bytes).contains(r#""url":"/events.json""#));
+
+        let js = asyncapi.file("index.js").unwrap().unwrap();
+        assert_eq!(js.bytes, AsyncApiReactStaticFile::Js.bytes());
+        assert_eq!(js.content_type, "text/javascript; charset=utf-8");
+
+        asyncapi.override_file_path(
+            AsyncApiReactStaticFile::Css,
+            "https://cdn.example.com/default.min.css".to_string(),
+        );
+        assert!(asyncapi.file("default.min.css").unwrap().is_none());
+    }
+}
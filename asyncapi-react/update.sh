@@ -0,0 +1,17 @@
+#!/bin/bash
+
+set -e -o pipefail
+
+if [ -z "$1" ]; then
+    echo "USAGE: $0 <version>"
+    echo "Example: $0 v2.6.5"
+    exit 1
+fi
+
+VERSION="$1"
+
+curl -o res/LICENSE https://raw.githubusercontent.com/asyncapi/asyncapi-react/refs/tags/$VERSION/LICENSE
+curl -o res/index.js https://cdn.jsdelivr.net/npm/@asyncapi/react-component@${VERSION#v}/browser/standalone/index.js
+curl -o res/default.min.css https://cdn.jsdelivr.net/npm/@asyncapi/react-component@${VERSION#v}/styles/default.min.css
+
+sed -i "s/<!-- version -->.*$/<!-- version -->The version of the AsyncAPI React component included in this crate is $VERSION./" README.md
@@ -0,0 +1,7 @@
+/*
+ * Placeholder for the GraphiQL bundle.
+ *
+ * Run `./update.sh <version>` in the crate directory to download the actual
+ * file from the graphiql npm package.
+ */
+console.error("GraphiQL has not been bundled; run update.sh to download it.");
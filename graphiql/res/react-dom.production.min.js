@@ -0,0 +1,7 @@
+/*
+ * Placeholder for the React DOM bundle.
+ *
+ * Run `./update.sh <version>` in the crate directory to download the actual
+ * file from the react-dom npm package.
+ */
+console.error("React DOM has not been bundled; run update.sh to download it.");
@@ -0,0 +1,712 @@
+//! This crate implements necessary boilerplate code to serve [GraphiQL] via
+//! web server. It provides a simple API to configure GraphiQL and serve it via
+//! a web server. The crate is deliberately kept simple and does not implement
+//! any web server (or GraphQL server) specific code. It is up to the user to
+//! implement the web server specific code for the web framework of choice.
+//!
+//! It does not download GraphiQL from the internet, but rather includes the
+//! necessary static files (including [React], which GraphiQL is built with)
+//! in the crate. This reduces the number of build dependencies and makes it
+//! easy to use the crate offline.
+//!
+//! GraphiQL reads the schema by sending an introspection query to the GraphQL
+//! endpoint, so there is no separate spec to point it to. It implements the
+//! [`OpenApiGui`] trait nevertheless, so it can be served by the same code as
+//! the OpenAPI GUIs. OpenAPI spec URLs are ignored, though, as they are not
+//! GraphQL endpoints; the endpoint is set with [`Config::endpoint`].
+//!
+//! # Attribution
+//!
+//! [GraphiQL] and [React] included in this crate are licensed under MIT.
+//!
+//! [GraphiQL]: https://github.com/graphql/graphiql
+//! [React]: https://react.dev/
+
+#![warn(missing_docs, rustdoc::missing_crate_level_docs)]
+
+use std::borrow::Cow;
+use std::error::Error;
+
+use openapi_gui_core::{FilePaths, GuiFile, OpenApiGui, SpecUrl, StaticFile, escape_html};
+use serde::ser::{Error as _, SerializeMap};
+use serde::{Serialize, Serializer};
+
+/// Entry point for serving GraphiQL in application. It provides builder style
+/// chainable configuration methods for configuring the GraphQL endpoint and
+/// the initial state of the editors.
+///
+/// # Examples
+///
+/// ```
+/// # use graphiql_redist::GraphiQl;
+/// let mut graphiql = GraphiQl::new();
+/// graphiql.config().endpoint("/api/graphql");
+/// let static_files = GraphiQl::static_files(); // static files that are needed to be served
+/// let html = graphiql.serve()?;
+/// # Ok::<(), Box<dyn std::error::Error + Send + Sync>>(())
+/// ```
+#[non_exhaustive]
+#[derive(Debug, Clone)]
+pub struct GraphiQl {
+    title: Cow<'static, str>,
+    config: Config,
+    file_paths: FilePaths<GraphiQlStaticFile>,
+}
+
+impl Default for GraphiQl {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl GraphiQl {
+    /// Create a new [`GraphiQl`] with the default configuration.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use graphiql_redist::GraphiQl;
+    /// let graphiql = GraphiQl::new();
+    /// ```
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            title: Cow::Borrowed("GraphiQL"),
+            config: Config::new(),
+            file_paths: FilePaths::new(),
+        }
+    }
+
+    /// Return a mutable reference to the config, allowing to modify it.
+    ///
+    /// This is useful for setting up GraphiQL with custom settings, but also
+    /// for setting up the URL of the GraphQL endpoint.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use graphiql_redist::GraphiQl;
+    /// let mut graphiql = GraphiQl::new();
+    /// graphiql
+    ///     .config()
+    ///     .endpoint("/api/graphql")
+    ///     .default_query("{ users { id name } }");
+    /// ```
+    pub fn config(&mut self) -> &mut Config {
+        &mut self.config
+    }
+
+    /// Sets the title for the GraphiQL page.
+    ///
+    /// The title will be displayed in the browser tab.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use graphiql_redist::GraphiQl;
+    /// let mut graphiql = GraphiQl::new();
+    /// graphiql.title("My GraphQL API");
+    /// ```
+    pub fn title(&mut self, title: impl Into<Cow<'static, str>>) -> &mut Self {
+        self.title = title.into();
+        self
+    }
+
+    /// Returns a reference to all static files required by GraphiQL.
+    ///
+    /// Each file is paired with its corresponding [`GraphiQlStaticFile`] enum
+    /// variant for identification.
+    ///
+    /// # Returns
+    ///
+    /// A static slice of tuples containing the file identifier and its raw
+    /// content.
+    #[must_use]
+    pub fn static_files() -> &'static [(GraphiQlStaticFile, &'static [u8])] {
+        &[
+            (
+                GraphiQlStaticFile::Js,
+                include_bytes!("../res/graphiql.min.js"),
+            ),
+            (
+                GraphiQlStaticFile::Css,
+                include_bytes!("../res/graphiql.min.css"),
+            ),
+            (
+                GraphiQlStaticFile::ReactJs,
+                include_bytes!("../res/react.production.min.js"),
+            ),
+            (
+                GraphiQlStaticFile::ReactDomJs,
+                include_bytes!("../res/react-dom.production.min.js"),
+            ),
+        ]
+    }
+
+    /// Overrides the path for a specific static file.
+    ///
+    /// This method allows customizing the URL paths where static files are
+    /// served from. This is useful when integrating with web frameworks
+    /// that have specific routing requirements or when serving files from a
+    /// CDN or different location.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use graphiql_redist::{GraphiQl, GraphiQlStaticFile};
+    /// let mut graphiql = GraphiQl::new();
+    /// graphiql.override_file_path(GraphiQlStaticFile::Css, "/assets/graphiql.css".to_string());
+    /// ```
+    pub fn override_file_path(&mut self, static_file: GraphiQlStaticFile, path: String) {
+        self.file_paths.set(static_file, path);
+    }
+
+    /// Looks up the file that should be served under the given path.
+    ///
+    /// The path is relative to the location GraphiQL is exposed at. An empty
+    /// path (or `index.html`) resolves to the HTML page generated by
+    /// [`GraphiQl::serve`], while the remaining paths are matched against the
+    /// (possibly overridden) relative paths of the static files. Leading
+    /// slashes are ignored.
+    ///
+    /// Static files whose path has been overridden with an absolute path or a
+    /// URL are not served, as the generated page does not reference them
+    /// relative to the GraphiQL location.
+    ///
+    /// # Returns
+    ///
+    /// The file to be served, or `None` if there is no file under given path.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the GraphiQL config fails to be serialized.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use graphiql_redist::GraphiQl;
+    /// let mut graphiql = GraphiQl::new();
+    /// graphiql.config().endpoint("/api/graphql");
+    ///
+    /// let index = graphiql.file("/")?.expect("index should be present");
+    /// assert_eq!(index.content_type, "text/html; charset=utf-8");
+    ///
+    /// let js = graphiql
+    ///     .file("/graphiql.min.js")?
+    ///     .expect("JS should be present");
+    /// assert_eq!(js.content_type, "text/javascript; charset=utf-8");
+    ///
+    /// assert!(graphiql.file("/does-not-exist.js")?.is_none());
+    /// # Ok::<(), Box<dyn std::error::Error + Send + Sync>>(())
+    /// ```
+    pub fn file(
+        &self,
+        path: &str,
+    ) -> Result<Option<GraphiQlFile<'static>>, Box<dyn Error + Send + Sync>> {
+        OpenApiGui::file(self, path)
+    }
+
+    /// Generates the HTML for the GraphiQL page.
+    ///
+    /// This method creates a complete HTML document that references the
+    /// GraphiQL and React JavaScript bundles and the GraphiQL stylesheet, and
+    /// renders GraphiQL with the settings specified in the [`Config`] object.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the GraphiQL config fails to be serialized.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use graphiql_redist::GraphiQl;
+    /// let mut graphiql = GraphiQl::new();
+    /// graphiql.config().endpoint("/api/graphql");
+    /// let html = graphiql.serve()?;
+    /// # Ok::<(), Box<dyn std::error::Error + Send + Sync>>(())
+    /// ```
+    pub fn serve(&self) -> Result<String, Box<dyn Error + Send + Sync>> {
+        let title = escape_html(&self.title);
+        let js_path = escape_html(self.file_paths.get(GraphiQlStaticFile::Js));
+        let css_path = escape_html(self.file_paths.get(GraphiQlStaticFile::Css));
+        let react_js_path = escape_html(self.file_paths.get(GraphiQlStaticFile::ReactJs));
+        let react_dom_js_path = escape_html(self.file_paths.get(GraphiQlStaticFile::ReactDomJs));
+        // neither the fetcher options nor the props may end the script
+        let fetcher_options = serde_json::to_string(&FetcherOptions {
+            url: &self.config.endpoint,
+            subscription_url: self.config.subscription_url.as_deref(),
+        })?
+        .replace("</", r"<\/");
+        let props = serde_json::to_string(&self.config)?.replace("</", r"<\/");
+
+        Ok(format!(
+            r#"<!DOCTYPE html>
+<html lang="en">
+<head>
+    <meta charset="UTF-8">
+    <meta name="viewport" content="width=device-width, initial-scale=1">
+    <title>{title}</title>
+    <link rel="stylesheet" type="text/css" href="{css_path}">
+    <style>
+        body {{
+            margin: 0;
+        }}
+        #graphiql {{
+            height: 100vh;
+        }}
+    </style>
+</head>
+<body>
+<div id="graphiql"></div>
+<script src="{react_js_path}" charset="UTF-8"></script>
+<script src="{react_dom_js_path}" charset="UTF-8"></script>
+<script src="{js_path}" charset="UTF-8"></script>
+<script>
+    const fetcher = GraphiQL.createFetcher({fetcher_options});
+    const props = {props};
+    ReactDOM.createRoot(document.getElementById("graphiql")).render(
+        React.createElement(GraphiQL, Object.assign({{ fetcher }}, props))
+    );
+</script>
+</body>
+</html>
+"#
+        ))
+    }
+}
+
+/// Options passed to `GraphiQL.createFetcher`.
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct FetcherOptions<'a> {
+    url: &'a str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    subscription_url: Option<&'a str>,
+}
+
+/// Represents the static files required by GraphiQL.
+#[non_exhaustive]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum GraphiQlStaticFile {
+    /// The GraphiQL JavaScript bundle.
+    Js,
+    /// The GraphiQL stylesheet.
+    Css,
+    /// The React JavaScript bundle, required by GraphiQL.
+    ReactJs,
+    /// The React DOM JavaScript bundle, required by GraphiQL.
+    ReactDomJs,
+}
+
+impl GraphiQlStaticFile {
+    /// Returns a slice containing all available GraphiQL static files.
+    #[must_use]
+    pub fn all() -> &'static [Self] {
+        &[
+            GraphiQlStaticFile::Js,
+            GraphiQlStaticFile::Css,
+            GraphiQlStaticFile::ReactJs,
+            GraphiQlStaticFile::ReactDomJs,
+        ]
+    }
+
+    /// Returns the filename for a specific static file.
+    #[must_use]
+    pub fn file_name(&self) -> &'static str {
+        match self {
+            GraphiQlStaticFile::Js => "graphiql.min.js",
+            GraphiQlStaticFile::Css => "graphiql.min.css",
+            GraphiQlStaticFile::ReactJs => "react.production.min.js",
+            GraphiQlStaticFile::ReactDomJs => "react-dom.production.min.js",
+        }
+    }
+
+    /// Returns the static file with given filename, if there is one.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use graphiql_redist::GraphiQlStaticFile;
+    /// assert_eq!(
+    ///     GraphiQlStaticFile::from_file_name("graphiql.min.css"),
+    ///     Some(GraphiQlStaticFile::Css)
+    /// );
+    /// assert_eq!(GraphiQlStaticFile::from_file_name("index.html"), None);
+    /// ```
+    #[must_use]
+    pub fn from_file_name(file_name: &str) -> Option<Self> {
+        Self::all()
+            .iter()
+            .copied()
+            .find(|file| file.file_name() == file_name)
+    }
+
+    /// Returns the content type of a specific static file, suitable for use in
+    /// the `Content-Type` header.
+    #[must_use]
+    pub fn content_type(&self) -> &'static str {
+        match self {
+            GraphiQlStaticFile::Js
+            | GraphiQlStaticFile::ReactJs
+            | GraphiQlStaticFile::ReactDomJs => "text/javascript; charset=utf-8",
+            GraphiQlStaticFile::Css => "text/css; charset=utf-8",
+        }
+    }
+
+    /// Returns the raw content of a specific static file.
+    ///
+    /// This is the same content as returned by [`GraphiQl::static_files`].
+    #[must_use]
+    #[expect(clippy::missing_panics_doc)]
+    pub fn bytes(&self) -> &'static [u8] {
+        GraphiQl::static_files()
+            .iter()
+            .find(|(file, _)| file == self)
+            .map(|(_, bytes)| *bytes)
+            .expect("all files should be present")
+    }
+}
+
+impl StaticFile for GraphiQlStaticFile {
+    fn all() -> &'static [Self] {
+        Self::all()
+    }
+
+    fn file_name(&self) -> &'static str {
+        self.file_name()
+    }
+
+    fn content_type(&self) -> &'static str {
+        self.content_type()
+    }
+
+    fn bytes(&self) -> &'static [u8] {
+        self.bytes()
+    }
+}
+
+impl OpenApiGui for GraphiQl {
+    type StaticFile = GraphiQlStaticFile;
+
+    fn set_title(&mut self, title: Cow<'static, str>) {
+        self.title = title;
+    }
+
+    /// Does nothing, as the URLs point to OpenAPI specs, not to a GraphQL
+    /// endpoint. Use [`Config::endpoint`] to set the endpoint instead.
+    fn set_spec_urls(&mut self, _urls: Vec<SpecUrl>) {}
+
+    fn file_paths(&self) -> &FilePaths<GraphiQlStaticFile> {
+        &self.file_paths
+    }
+
+    fn file_paths_mut(&mut self) -> &mut FilePaths<GraphiQlStaticFile> {
+        &mut self.file_paths
+    }
+
+    fn render_index(&self) -> Result<String, Box<dyn Error + Send + Sync>> {
+        self.serve()
+    }
+}
+
+/// Represents servable file of GraphiQL. This is returned by
+/// [`GraphiQl::file`] to serve GraphiQL files via web server.
+pub type GraphiQlFile<'a> = GuiFile<'a>;
+
+/// Which of the editor tools (the variables and headers editors) are visible
+/// by default.
+#[non_exhaustive]
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum EditorToolsVisibility {
+    /// The editor tools are hidden.
+    Hidden,
+    /// The editor tools are visible, with the variables editor open.
+    Variables,
+    /// The editor tools are visible, with the headers editor open.
+    Headers,
+}
+
+impl Serialize for EditorToolsVisibility {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            EditorToolsVisibility::Hidden => serializer.serialize_bool(false),
+            EditorToolsVisibility::Variables => serializer.serialize_str("variables"),
+            EditorToolsVisibility::Headers => serializer.serialize_str("headers"),
+        }
+    }
+}
+
+/// Object used to alter GraphiQL settings.
+///
+/// Only the options that were set explicitly are passed to GraphiQL, so its
+/// own defaults apply to everything else.
+///
+/// # Examples
+///
+/// ```
+/// # use graphiql_redist::Config;
+/// let mut config = Config::new();
+/// config
+///     .endpoint("/api/graphql")
+///     .subscription_url("wss://example.com/api/graphql/ws")
+///     .default_header("Authorization", "Bearer <token>")
+///     .default_query("{ users { id name } }");
+/// ```
+#[non_exhaustive]
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Config {
+    /// URL of the GraphQL endpoint. This is passed to the fetcher rather than
+    /// GraphiQL itself.
+    #[serde(skip)]
+    endpoint: String,
+
+    /// URL of the WebSocket GraphQL endpoint. This is passed to the
+    /// fetcher rather than GraphiQL itself.
+    #[serde(skip)]
+    subscription_url: Option<String>,
+
+    /// Query shown in the editor when there is no query stored in the
+    /// browser.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    default_query: Option<String>,
+
+    /// Headers shown in the headers editor when there are no headers stored
+    /// in the browser. GraphiQL expects them as a JSON string.
+    #[serde(
+        skip_serializing_if = "Vec::is_empty",
+        serialize_with = "serialize_headers"
+    )]
+    default_headers: Vec<(String, String)>,
+
+    /// Which of the editor tools are visible by default.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    default_editor_tools_visibility: Option<EditorToolsVisibility>,
+
+    /// Whether the headers editor is enabled.
+    #[serde(
+        rename = "isHeadersEditorEnabled",
+        skip_serializing_if = "Option::is_none"
+    )]
+    headers_editor_enabled: Option<bool>,
+
+    /// Whether the headers are persisted in the browser storage.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    should_persist_headers: Option<bool>,
+}
+
+/// Serializes the headers as a pretty-printed JSON object inside a string,
+/// keeping their order.
+fn serialize_headers<S: Serializer>(
+    headers: &[(String, String)],
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    struct Headers<'a>(&'a [(String, String)]);
+
+    impl Serialize for Headers<'_> {
+        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            let mut map = serializer.serialize_map(Some(self.0.len()))?;
+            for (name, value) in self.0 {
+                map.serialize_entry(name, value)?;
+            }
+            map.end()
+        }
+    }
+
+    let json = serde_json::to_string_pretty(&Headers(headers)).map_err(S::Error::custom)?;
+    serializer.serialize_str(&json)
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            endpoint: DEFAULT_ENDPOINT.to_string(),
+            subscription_url: None,
+            default_query: None,
+            default_headers: Vec::new(),
+            default_editor_tools_visibility: None,
+            headers_editor_enabled: None,
+            should_persist_headers: None,
+        }
+    }
+}
+
+const DEFAULT_ENDPOINT: &str = "/graphql";
+
+impl Config {
+    /// Constructs a new [`Config`] with GraphiQL's default settings and the
+    /// endpoint set to `/graphql`.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the URL of the GraphQL endpoint queries are sent to.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use graphiql_redist::Config;
+    /// let mut config = Config::new();
+    /// config.endpoint("/api/graphql");
+    /// ```
+    pub fn endpoint<S: Into<String>>(&mut self, endpoint: S) -> &mut Self {
+        self.endpoint = endpoint.into();
+        self
+    }
+
+    /// Sets the URL of the WebSocket GraphQL endpoint subscriptions are
+    /// sent to. By default, subscriptions are not supported.
+    ///
+    /// Unlike the endpoint, this has to be an absolute `ws://` or `wss://`
+    /// URL.
+    pub fn subscription_url<S: Into<String>>(&mut self, subscription_url: S) -> &mut Self {
+        self.subscription_url = Some(subscription_url.into());
+        self
+    }
+
+    /// Sets the query shown in the editor when there is no query stored in
+    /// the browser. By default, GraphiQL shows a comment explaining how to
+    /// use it.
+    pub fn default_query<S: Into<String>>(&mut self, default_query: S) -> &mut Self {
+        self.default_query = Some(default_query.into());
+        self
+    }
+
+    /// Adds a header shown in the headers editor when there are no headers
+    /// stored in the browser. The headers are sent along with every request,
+    /// unless removed by the user.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use graphiql_redist::Config;
+    /// let mut config = Config::new();
+    /// config
+    ///     .default_header("Authorization", "Bearer <token>")
+    ///     .default_header("X-Tenant", "example");
+    /// ```
+    pub fn default_header<N: Into<String>, V: Into<String>>(
+        &mut self,
+        name: N,
+        value: V,
+    ) -> &mut Self {
+        self.default_headers.push((name.into(), value.into()));
+        self
+    }
+
+    /// Sets which of the editor tools are visible by default. By default,
+    /// they are visible only if there are variables or headers to show.
+    pub fn default_editor_tools_visibility(
+        &mut self,
+        visibility: EditorToolsVisibility,
+    ) -> &mut Self {
+        self.default_editor_tools_visibility = Some(visibility);
+        self
+    }
+
+    /// Sets whether the headers editor is enabled. Default is `true`.
+    pub fn headers_editor_enabled(&mut self, headers_editor_enabled: bool) -> &mut Self {
+        self.headers_editor_enabled = Some(headers_editor_enabled);
+        self
+    }
+
+    /// Sets whether the headers are persisted in the browser storage along
+    /// with the queries. Default is `false`, as headers often contain
+    /// credentials.
+    pub fn should_persist_headers(&mut self, should_persist_headers: bool) -> &mut Self {
+        self.should_persist_headers = Some(should_persist_headers);
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serve_default() {
+        let html = GraphiQl::new().serve().unwrap();
+
+        assert!(html.contains("<title>GraphiQL</title>"));
+        assert!(
+            html.contains(r#"<link rel="stylesheet" type="text/css" href="./graphiql.min.css">"#)
+        );
+        assert!(
+            html.contains(r#"<script src="./react.production.min.js" charset="UTF-8"></script>"#)
+        );
+        assert!(html.contains(r#"const fetcher = GraphiQL.createFetcher({"url":"/graphql"});"#));
+        assert!(html.contains("const props = {};"));
+    }
+
+    #[test]
+    fn serve_escapes_script_end() {
+        let mut graphiql = GraphiQl::new();
+        graphiql
+            .config()
+            .endpoint("/graphql?</script>")
+            .default_query("# </script>");
+
+        let html = graphiql.serve().unwrap();
+
+        assert_eq!(html.matches("</script>").count(), 4);
+        assert!(html.contains(r#"{"url":"/graphql?<\/script>"}"#));
+        assert!(html.contains(r##"{"defaultQuery":"# <\/script>"}"##));
+    }
+
+    #[test]
+    fn config_serialization() {
+        let mut config = Config::new();
+        config
+            .endpoint("/api/graphql")
+            .subscription_url("wss://example.com/ws")
+            .default_query("{ users { id } }")
+            .default_header("X-Tenant", "example")
+            .default_header("Authorization", "Bearer token")
+            .default_editor_tools_visibility(EditorToolsVisibility::Headers)
+            .headers_editor_enabled(true)
+            .should_persist_headers(false);
+
+        assert_eq!(
+            serde_json::to_value(&config).unwrap(),
+            serde_json::json!({
+                "defaultQuery": "{ users { id } }",
+                "defaultHeaders": "{\n  \"X-Tenant\": \"example\",\n  \"Authorization\": \"Bearer token\"\n}",
+                "defaultEditorToolsVisibility": "headers",
+                "isHeadersEditorEnabled": true,
+                "shouldPersistHeaders": false,
+            })
+        );
+        assert_eq!(
+            serde_json::to_string(&FetcherOptions {
+                url: &config.endpoint,
+                subscription_url: config.subscription_url.as_deref(),
+            })
+            .unwrap(),
+            r#"{"url":"/api/graphql","subscriptionUrl":"wss://example.com/ws"}"#
+        );
+    }
+
+    #[test]
+    fn file_lookup() {
+        let mut graphiql = GraphiQl::new();
+        graphiql.config().endpoint("/api/graphql");
+        graphiql.set_spec_urls(vec![SpecUrl::new("/openapi.json")]);
+
+        let index = graphiql.file("/").unwrap().unwrap();
+        assert!(String::from_utf8_lossy(&index.bytes).contains(r#"{"url":"/api/graphql"}"#));
+
+        let react_dom = graphiql
+            .file("react-dom.production.min.js")
+            .unwrap()
+            .unwrap();
+        assert_eq!(react_dom.bytes, GraphiQlStaticFile::ReactDomJs.bytes());
+        assert_eq!(react_dom.content_type, "text/javascript; charset=utf-8");
+
+        graphiql.override_file_path(
+            GraphiQlStaticFile::ReactJs,
+            "https://cdn.example.com/react.production.min.js".to_string(),
+        );
+        assert!(graphiql.file("react.production.min.js").unwrap().is_none());
+    }
+}
@@ -0,0 +1,21 @@
+#!/bin/bash
+
+set -e -o pipefail
+
+if [ -z "$1" ]; then
+    echo "USAGE: $0 <version> [react-version]"
+    echo "Example: $0 v3.8.3 18.3.1"
+    exit 1
+fi
+
+VERSION="$1"
+REACT_VERSION="${2:-18.3.1}"
+
+curl -o res/LICENSE https://raw.githubusercontent.com/graphql/graphiql/refs/tags/graphiql%40${VERSION#v}/LICENSE
+curl -o res/graphiql.min.js https://cdn.jsdelivr.net/npm/graphiql@${VERSION#v}/graphiql.min.js
+curl -o res/graphiql.min.css https://cdn.jsdelivr.net/npm/graphiql@${VERSION#v}/graphiql.min.css
+curl -o res/LICENSE-react https://raw.githubusercontent.com/facebook/react/refs/tags/v$REACT_VERSION/LICENSE
+curl -o res/react.production.min.js https://cdn.jsdelivr.net/npm/react@$REACT_VERSION/umd/react.production.min.js
+curl -o res/react-dom.production.min.js https://cdn.jsdelivr.net/npm/react-dom@$REACT_VERSION/umd/react-dom.production.min.js
+
+sed -i "s/<!-- version -->.*$/<!-- version -->The version of GraphiQL included in this crate is $VERSION, along with React $REACT_VERSION./" README.md
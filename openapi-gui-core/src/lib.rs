@@ -0,0 +1,615 @@
+//! Common types and traits shared by the OpenAPI GUI redistribution crates.
+//!
+//! Every GUI crate (Swagger UI, Scalar, ReDoc, RapiDoc, Stoplight Elements)
+//! implements the [`OpenApiGui`] trait. It exposes the operations a web
+//! framework integration needs: setting the page title and the OpenAPI spec
+//! URLs, rendering the index page and looking up the files that should be
+//! served under given paths. This allows the glue code for a web framework to
+//! be written once and used with any of the GUIs.
+//!
+//! Several GUIs can be served together, with a landing page allowing users to
+//! switch between them, using [`selector::GuiSelector`].
+//!
+//! # Examples
+//!
+//! ```
+//! use std::borrow::Cow;
+//!
+//! use openapi_gui_core::{OpenApiGui, SpecUrl};
+//!
+//! fn setup<G: OpenApiGui>(gui: &mut G) {
+//!     gui.set_title(Cow::Borrowed("My API"));
+//!     gui.set_spec_urls(vec![SpecUrl::new("/openapi.json")]);
+//! }
+//! ```
+
+#![warn(missing_docs, rustdoc::missing_crate_level_docs)]
+
+use std::borrow::Cow;
+use std::collections::HashMap;
+use std::error::Error;
+use std::fmt::{Debug, Display, Formatter};
+use std::hash::Hash;
+
+pub mod selector;
+
+/// The name of the index page, served in addition to the empty path.
+pub const INDEX_FILE_NAME: &str = "index.html";
+
+/// The content type of the index page, suitable for use in the `Content-Type`
+/// header.
+pub const INDEX_CONTENT_TYPE: &str = "text/html; charset=utf-8";
+
+/// A web based OpenAPI documentation GUI.
+///
+/// This trait captures the parts that are common to all the GUIs, so that
+/// web framework integrations can be written once. GUI specific settings are
+/// still configured through the inherent methods of the implementing types.
+///
+/// # Examples
+///
+/// Serving any GUI from a request handler:
+///
+/// ```
+/// use openapi_gui_core::OpenApiGui;
+///
+/// fn handle<G: OpenApiGui>(gui: &G, path: &str) -> Option<(String, Vec<u8>)> {
+///     let file = gui.file(path).ok()??;
+///     Some((file.content_type, file.bytes.into_owned()))
+/// }
+/// ```
+pub trait OpenApiGui {
+    /// The static files the GUI consists of.
+    type StaticFile: StaticFile;
+
+    /// Sets the title of the generated HTML page.
+    fn set_title(&mut self, title: Cow<'static, str>);
+
+    /// Sets the URLs of the OpenAPI specs to display.
+    ///
+    /// GUIs that can only display a single spec use the primary URL, as
+    /// returned by [`SpecUrl::primary_of`]. Passing an empty list unsets the
+    /// spec URL, if the GUI allows that.
+    fn set_spec_urls(&mut self, urls: Vec<SpecUrl>);
+
+    /// Returns the paths the static files are referenced under by the
+    /// generated HTML page.
+    fn file_paths(&self) -> &FilePaths<Self::StaticFile>;
+
+    /// Returns a mutable reference to the paths the static files are
+    /// referenced under by the generated HTML page.
+    fn file_paths_mut(&mut self) -> &mut FilePaths<Self::StaticFile>;
+
+    /// Overrides the path for a specific static file.
+    ///
+    /// This is useful when integrating with web frameworks that have specific
+    /// routing requirements or when serving files from a CDN.
+    fn override_file_path(&mut self, static_file: Self::StaticFile, path: String) {
+        self.file_paths_mut().set(static_file, path);
+    }
+
+    /// Renders the HTML index page of the GUI.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the configuration of the GUI could not be
+    /// serialized.
+    fn render_index(&self) -> Result<String, Box<dyn Error + Send + Sync>>;
+
+    /// Looks up the file that should be served under the given path.
+    ///
+    /// The path is relative to the location the GUI is exposed at. An empty
+    /// path (or `index.html`) resolves to the page generated by
+    /// [`OpenApiGui::render_index`], while the remaining paths are matched
+    /// against the relative paths of the static files, as returned by
+    /// [`FilePaths::find`]. Leading slashes are ignored.
+    ///
+    /// # Returns
+    ///
+    /// The file to be served, or `None` if there is no file under given path.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the index page could not be rendered.
+    fn file(&self, path: &str) -> Result<Option<GuiFile<'static>>, Box<dyn Error + Send + Sync>> {
+        if is_index_path(path) {
+            return Ok(Some(GuiFile::index(self.render_index()?)));
+        }
+
+        Ok(self.file_paths().find(path).map(GuiFile::from_static_file))
+    }
+}
+
+/// A static file that is a part of a GUI.
+///
+/// This is implemented by the static file enums of the GUI crates.
+pub trait StaticFile: Copy + Eq + Hash + Debug + Send + Sync + 'static {
+    /// Returns a slice containing all the static files of the GUI.
+    fn all() -> &'static [Self];
+
+    /// Returns the filename of the static file.
+    fn file_name(&self) -> &'static str;
+
+    /// Returns the content type of the static file, suitable for use in the
+    /// `Content-Type` header.
+    fn content_type(&self) -> &'static str;
+
+    /// Returns the raw content of the static file.
+    fn bytes(&self) -> &'static [u8];
+
+    /// Returns the path the static file is referenced under by default,
+    /// which is the filename relative to the GUI location.
+    fn default_path(&self) -> String {
+        format!("./{}", self.file_name())
+    }
+}
+
+/// The paths the static files of a GUI are referenced under.
+///
+/// Each static file has a path, which is its filename relative to the GUI
+/// location unless it has been overridden with [`FilePaths::set`].
+///
+/// # Examples
+///
+/// ```
+/// # use openapi_gui_core::{FilePaths, StaticFile};
+/// # #[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+/// # struct Js;
+/// # impl StaticFile for Js {
+/// #     fn all() -> &'static [Self] { &[Js] }
+/// #     fn file_name(&self) -> &'static str { "app.js" }
+/// #     fn content_type(&self) -> &'static str { "text/javascript" }
+/// #     fn bytes(&self) -> &'static [u8] { b"" }
+/// # }
+/// let mut file_paths = FilePaths::new();
+/// assert_eq!(file_paths.get(Js), "./app.js");
+/// assert_eq!(file_paths.find("/app.js"), Some(Js));
+///
+/// file_paths.set(Js, "https://cdn.example.com/app.js".to_string());
+/// assert_eq!(file_paths.relative(Js), None);
+/// assert_eq!(file_paths.find("app.js"), None);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FilePaths<F: StaticFile> {
+    paths: HashMap<F, String>,
+}
+
+impl<F: StaticFile> Default for FilePaths<F> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<F: StaticFile> FilePaths<F> {
+    /// Creates a new path table with all the files at their default paths.
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            paths: F::all()
+                .iter()
+                .map(|file| (*file, file.default_path()))
+                .collect(),
+        }
+    }
+
+    /// Returns the path given static file is referenced under.
+    #[must_use]
+    #[expect(clippy::missing_panics_doc)]
+    pub fn get(&self, static_file: F) -> &str {
+        self.paths
+            .get(&static_file)
+            .expect("all files should be present")
+    }
+
+    /// Overrides the path given static file is referenced under.
+    pub fn set(&mut self, static_file: F, path: String) {
+        self.paths.insert(static_file, path);
+    }
+
+    /// Returns the path of given static file relative to the GUI location,
+    /// or `None` if it has been overridden with an absolute path or a URL.
+    #[must_use]
+    pub fn relative(&self, static_file: F) -> Option<&str> {
+        let file_path = self.get(static_file);
+        let file_path = file_path.strip_prefix("./").unwrap_or(file_path);
+        let is_relative = !file_path.starts_with('/') && !file_path.contains("://");
+
+        is_relative.then_some(file_path)
+    }
+
+    /// Returns the static file served under given path relative to the GUI
+    /// location. Leading slashes are ignored.
+    ///
+    /// Static files whose path has been overridden with an absolute path or a
+    /// URL are never returned, as the generated page does not reference them
+    /// relative to the GUI location.
+    #[must_use]
+    pub fn find(&self, path: &str) -> Option<F> {
+        let path = path.trim_start_matches('/');
+
+        F::all()
+            .iter()
+            .copied()
+            .find(|static_file| self.relative(*static_file) == Some(path))
+    }
+}
+
+/// Represents a servable file of a GUI. This is returned by
+/// [`OpenApiGui::file`] to serve the GUI files via web server.
+#[non_exhaustive]
+#[derive(Debug, Clone)]
+pub struct GuiFile<'a> {
+    /// Content of the file as [`Cow`] [`slice`] of bytes.
+    pub bytes: Cow<'a, [u8]>,
+    /// Content type of the file e.g `"text/xml"`.
+    pub content_type: String,
+}
+
+impl<'a> GuiFile<'a> {
+    /// Creates a new file with given content and content type.
+    #[must_use]
+    pub fn new(bytes: impl Into<Cow<'a, [u8]>>, content_type: impl Into<String>) -> Self {
+        Self {
+            bytes: bytes.into(),
+            content_type: content_type.into(),
+        }
+    }
+
+    /// Creates a new HTML index page file with given content.
+    #[must_use]
+    pub fn index(html: String) -> Self {
+        Self::new(html.into_bytes(), INDEX_CONTENT_TYPE)
+    }
+
+    /// Creates a new file with the content and content type of given static
+    /// file.
+    #[must_use]
+    pub fn from_static_file<F: StaticFile>(static_file: F) -> Self {
+        Self::new(static_file.bytes(), static_file.content_type())
+    }
+}
+
+/// Returns whether given path, relative to the GUI location, points to the
+/// index page. Leading slashes are ignored.
+///
+/// # Examples
+///
+/// ```
+/// # use openapi_gui_core::is_index_path;
+/// assert!(is_index_path("/"));
+/// assert!(is_index_path("index.html"));
+/// assert!(!is_index_path("/app.js"));
+/// ```
+#[must_use]
+pub fn is_index_path(path: &str) -> bool {
+    let path = path.trim_start_matches('/');
+    path.is_empty() || path == INDEX_FILE_NAME
+}
+
+/// The URL of an OpenAPI spec displayed by a GUI.
+///
+/// # Examples
+///
+/// ```
+/// # use openapi_gui_core::SpecUrl;
+/// let urls = vec![
+///     SpecUrl::new("/api/v1/openapi.json").name("v1"),
+///     SpecUrl::new("/api/v2/openapi.json")
+///         .name("v2")
+///         .primary(true),
+/// ];
+/// assert_eq!(
+///     SpecUrl::primary_of(&urls).map(|url| url.url.as_str()),
+///     Some("/api/v2/openapi.json")
+/// );
+/// ```
+#[non_exhaustive]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SpecUrl {
+    /// The URL of the spec.
+    pub url: String,
+    /// The display name of the spec, used by GUIs that can display multiple
+    /// specs.
+    pub name: Option<String>,
+    /// Whether the spec should be displayed by default.
+    pub primary: bool,
+}
+
+impl SpecUrl {
+    /// Creates a new non-primary spec URL without a name.
+    #[must_use]
+    pub fn new(url: impl Into<String>) -> Self {
+        Self {
+            url: url.into(),
+            name: None,
+            primary: false,
+        }
+    }
+
+    /// Sets the display name of the spec.
+    #[must_use]
+    pub fn name(mut self, name: impl Into<String>) -> Self {
+        self.name = Some(name.into());
+        self
+    }
+
+    /// Sets whether the spec should be displayed by default.
+    #[must_use]
+    pub fn primary(mut self, primary: bool) -> Self {
+        self.primary = primary;
+        self
+    }
+
+    /// Returns the spec that should be displayed by default: the first one
+    /// marked as primary or, if there is none, the first one.
+    #[must_use]
+    pub fn primary_of(urls: &[SpecUrl]) -> Option<&SpecUrl> {
+        urls.iter().find(|url| url.primary).or_else(|| urls.first())
+    }
+}
+
+impl From<&str> for SpecUrl {
+    fn from(url: &str) -> Self {
+        Self::new(url)
+    }
+}
+
+impl From<String> for SpecUrl {
+    fn from(url: String) -> Self {
+        Self::new(url)
+    }
+}
+
+/// Escapes given text so that it can be safely put inside HTML element content
+/// or a double-quoted attribute value.
+///
+/// # Examples
+///
+/// ```
+/// # use openapi_gui_core::escape_html;
+/// assert_eq!(escape_html("plain"), "plain");
+/// assert_eq!(
+///     escape_html(r#"<a href="x">"#),
+///     "&lt;a href=&quot;x&quot;&gt;"
+/// );
+/// ```
+#[must_use]
+pub fn escape_html(text: &str) -> Cow<'_, str> {
+    if !text.contains(['&', '<', '>', '"', '\'']) {
+        return Cow::Borrowed(text);
+    }
+
+    let mut escaped = String::with_capacity(text.len() + 16);
+    for char in text.chars() {
+        match char {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&#39;"),
+            _ => escaped.push(char),
+        }
+    }
+    Cow::Owned(escaped)
+}
+
+/// A list of HTML attributes, rendered with their values escaped using
+/// [`escape_html`].
+///
+/// Attributes are only added if they have a value, so GUIs configured through
+/// element attributes can render just the settings that were set explicitly.
+/// Each attribute is rendered on its own line, indented with four spaces and
+/// preceded by a newline, so that it can be put right after the element name.
+///
+/// # Examples
+///
+/// ```
+/// # use openapi_gui_core::HtmlAttributes;
+/// let mut attributes = HtmlAttributes::new();
+/// attributes
+///     .text("spec-url", Some("/openapi.json?a=1&b=2"))
+///     .text("theme", None)
+///     .flag("allow-try", Some(false));
+///
+/// assert_eq!(
+///     format!("<rapi-doc{attributes}>"),
+///     "<rapi-doc\n    spec-url=\"/openapi.json?a=1&amp;b=2\"\n    allow-try=\"false\">"
+/// );
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct HtmlAttributes<'a> {
+    attributes: Vec<(&'a str, &'a str)>,
+}
+
+impl<'a> HtmlAttributes<'a> {
+    /// Creates an empty list of attributes.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds an attribute with a text value, if the value is set.
+    pub fn text(&mut self, name: &'a str, value: Option<&'a str>) -> &mut Self {
+        if let Some(value) = value {
+            self.attributes.push((name, value));
+        }
+        self
+    }
+
+    /// Adds an attribute with a `true` or `false` value, if the value is set.
+    pub fn flag(&mut self, name: &'a str, value: Option<bool>) -> &mut Self {
+        self.text(
+            name,
+            value.map(|value| if value { "true" } else { "false" }),
+        )
+    }
+}
+
+impl Display for HtmlAttributes<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        for (name, value) in &self.attributes {
+            write!(f, "\n    {name}=\"{}\"", escape_html(value))?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+    enum TestFile {
+        Js,
+        Css,
+    }
+
+    impl StaticFile for TestFile {
+        fn all() -> &'static [Self] {
+            &[TestFile::Js, TestFile::Css]
+        }
+
+        fn file_name(&self) -> &'static str {
+            match self {
+                TestFile::Js => "app.js",
+                TestFile::Css => "app.css",
+            }
+        }
+
+        fn content_type(&self) -> &'static str {
+            match self {
+                TestFile::Js => "text/javascript; charset=utf-8",
+                TestFile::Css => "text/css; charset=utf-8",
+            }
+        }
+
+        fn bytes(&self) -> &'static [u8] {
+            match self {
+                TestFile::Js => b"console.log(1);",
+                TestFile::Css => b"body {}",
+            }
+        }
+    }
+
+    #[derive(Debug)]
+    struct TestGui {
+        title: Cow<'static, str>,
+        spec_url: Option<String>,
+        file_paths: FilePaths<TestFile>,
+    }
+
+    impl OpenApiGui for TestGui {
+        type StaticFile = TestFile;
+
+        fn set_title(&mut self, title: Cow<'static, str>) {
+            self.title = title;
+        }
+
+        fn set_spec_urls(&mut self, urls: Vec<SpecUrl>) {
+            self.spec_url = SpecUrl::primary_of(&urls).map(|url| url.url.clone());
+        }
+
+        fn file_paths(&self) -> &FilePaths<TestFile> {
+            &self.file_paths
+        }
+
+        fn file_paths_mut(&mut self) -> &mut FilePaths<TestFile> {
+            &mut self.file_paths
+        }
+
+        fn render_index(&self) -> Result<String, Box<dyn Error + Send + Sync>> {
+            Ok(format!(
+                "<title>{}</title>{}",
+                self.title,
+                self.spec_url.as_deref().unwrap_or_default()
+            ))
+        }
+    }
+
+    fn test_gui() -> TestGui {
+        TestGui {
+            title: Cow::Borrowed("Test"),
+            spec_url: None,
+            file_paths: FilePaths::new(),
+        }
+    }
+
+    #[test]
+    fn file_paths_relative() {
+        let mut file_paths = FilePaths::<TestFile>::new();
+        assert_eq!(file_paths.relative(TestFile::Js), Some("app.js"));
+
+        file_paths.set(TestFile::Js, "static/app.js".to_string());
+        assert_eq!(file_paths.relative(TestFile::Js), Some("static/app.js"));
+        assert_eq!(file_paths.find("/static/app.js"), Some(TestFile::Js));
+        assert_eq!(file_paths.find("app.js"), None);
+
+        file_paths.set(TestFile::Css, "/assets/app.css".to_string());
+        assert_eq!(file_paths.relative(TestFile::Css), None);
+        assert_eq!(file_paths.find("assets/app.css"), None);
+    }
+
+    #[test]
+    fn gui_file() {
+        let mut gui = test_gui();
+        gui.set_title(Cow::Borrowed("My API"));
+        gui.set_spec_urls(vec![
+            SpecUrl::new("/v1.json"),
+            SpecUrl::new("/v2.json").primary(true),
+        ]);
+
+        let index = gui.file("/").unwrap().expect("index should be present");
+        assert_eq!(index.content_type, INDEX_CONTENT_TYPE);
+        assert_eq!(&*index.bytes, b"<title>My API</title>/v2.json");
+        assert!(gui.file("/index.html").unwrap().is_some());
+
+        let css = gui.file("app.css").unwrap().expect("CSS should be present");
+        assert_eq!(css.content_type, "text/css; charset=utf-8");
+        assert_eq!(&*css.bytes, b"body {}");
+
+        gui.override_file_path(TestFile::Css, "https://cdn.example.com/app.css".to_string());
+        assert!(gui.file("app.css").unwrap().is_none());
+        assert!(gui.file("/does-not-exist.js").unwrap().is_none());
+    }
+
+    #[test]
+    fn spec_url_primary_of() {
+        assert_eq!(SpecUrl::primary_of(&[]), None);
+
+        let urls = [SpecUrl::new("/a.json"), SpecUrl::new("/b.json")];
+        assert_eq!(SpecUrl::primary_of(&urls), Some(&urls[0]));
+
+        let urls = [
+            SpecUrl::new("/a.json"),
+            SpecUrl::new("/b.json").primary(true),
+            SpecUrl::new("/c.json").primary(true),
+        ];
+        assert_eq!(SpecUrl::primary_of(&urls), Some(&urls[1]));
+    }
+
+    #[test]
+    fn escape_html_special_chars() {
+        assert!(matches!(escape_html("no special chars"), Cow::Borrowed(_)));
+        assert_eq!(
+            escape_html(r#"<script>alert("x" & 'y')</script>"#),
+            "&lt;script&gt;alert(&quot;x&quot; &amp; &#39;y&#39;)&lt;/script&gt;"
+        );
+    }
+
+    #[test]
+    fn html_attributes() {
+        let mut attributes = HtmlAttributes::new();
+        assert_eq!(attributes.to_string(), "");
+
+        attributes
+            .text("title", Some(r#""><script>"#))
+            .text("unset", None)
+            .flag("enabled", Some(true))
+            .flag("unset-flag", None);
+
+        assert_eq!(
+            attributes.to_string(),
+            "\n    title=\"&quot;&gt;&lt;script&gt;\"\n    enabled=\"true\""
+        );
+    }
+}
@@ -0,0 +1,458 @@
+//! A landing page that lets users switch between several GUIs displaying the
+//! same OpenAPI spec.
+//!
+//! See [`GuiSelector`] for more details.
+
+use std::borrow::Cow;
+use std::error::Error;
+use std::fmt::{Debug, Write};
+
+use crate::{GuiFile, OpenApiGui, SpecUrl, escape_html, is_index_path};
+
+/// A composite of several GUIs, mounted under sub-paths of a single location.
+///
+/// The index page of the selector renders a tab for each GUI, which shows the
+/// GUI in a frame below the tabs. Each GUI is served under its own sub-path
+/// (e.g. `swagger/` and `redoc/`), and all the files are looked up with a
+/// single [`GuiSelector::file`] call, so only one route has to be registered
+/// in the web framework.
+///
+/// The GUIs reference their static files relative to their location, so the
+/// sub-paths have to be requested with a trailing slash (`swagger/`, not
+/// `swagger`). The links on the index page always include it.
+///
+/// # Examples
+///
+/// ```
+/// # use std::borrow::Cow;
+/// # use openapi_gui_core::selector::GuiSelector;
+/// # use openapi_gui_core::{FilePaths, OpenApiGui, SpecUrl, StaticFile};
+/// # #[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+/// # struct Js;
+/// # impl StaticFile for Js {
+/// #     fn all() -> &'static [Self] { &[Js] }
+/// #     fn file_name(&self) -> &'static str { "app.js" }
+/// #     fn content_type(&self) -> &'static str { "text/javascript" }
+/// #     fn bytes(&self) -> &'static [u8] { b"" }
+/// # }
+/// # #[derive(Debug, Default)]
+/// # struct Gui(FilePaths<Js>);
+/// # impl OpenApiGui for Gui {
+/// #     type StaticFile = Js;
+/// #     fn set_title(&mut self, _: Cow<'static, str>) {}
+/// #     fn set_spec_urls(&mut self, _: Vec<SpecUrl>) {}
+/// #     fn file_paths(&self) -> &FilePaths<Js> { &self.0 }
+/// #     fn file_paths_mut(&mut self) -> &mut FilePaths<Js> { &mut self.0 }
+/// #     fn render_index(&self) -> Result<String, Box<dyn std::error::Error + Send + Sync>> {
+/// #         Ok(String::new())
+/// #     }
+/// # }
+/// # let (swagger, redoc) = (Gui::default(), Gui::default());
+/// let mut selector = GuiSelector::new();
+/// selector
+///     .title("My API")
+///     .spec_urls(vec![SpecUrl::new("/openapi.json")])
+///     .add("swagger", "Swagger UI", swagger)
+///     .add("redoc", "ReDoc", redoc);
+///
+/// let index = selector.file("/")?.expect("index should be present");
+/// let js = selector.file("/redoc/app.js")?.expect("JS should be present");
+/// # Ok::<(), Box<dyn std::error::Error + Send + Sync>>(())
+/// ```
+#[derive(Debug)]
+pub struct GuiSelector {
+    title: Cow<'static, str>,
+    spec_urls: Vec<SpecUrl>,
+    tabs: Vec<Tab>,
+}
+
+#[derive(Debug)]
+struct Tab {
+    slug: String,
+    label: String,
+    gui: Box<dyn ErasedGui>,
+}
+
+impl Default for GuiSelector {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl GuiSelector {
+    /// Creates a new selector without any GUIs.
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            title: Cow::Borrowed("API Documentation"),
+            spec_urls: Vec::new(),
+            tabs: Vec::new(),
+        }
+    }
+
+    /// Sets the title of the index page.
+    ///
+    /// The title is also set on every GUI that is a part of the selector, so
+    /// that the browser tab shows the same title regardless of the GUI
+    /// selected.
+    pub fn title(&mut self, title: impl Into<Cow<'static, str>>) -> &mut Self {
+        self.title = title.into();
+        for tab in &mut self.tabs {
+            tab.gui.set_title(self.title.clone());
+        }
+        self
+    }
+
+    /// Sets the URLs of the OpenAPI specs displayed by all the GUIs, including
+    /// the ones added later.
+    pub fn spec_urls(&mut self, urls: Vec<SpecUrl>) -> &mut Self {
+        for tab in &mut self.tabs {
+            tab.gui.set_spec_urls(urls.clone());
+        }
+        self.spec_urls = urls;
+        self
+    }
+
+    /// Adds a GUI to the selector.
+    ///
+    /// The GUI is served under the `slug` sub-path and its tab is labeled
+    /// with `label`. The tabs are displayed in the order the GUIs have been
+    /// added, and the first one is selected by default. The title and the spec
+    /// URLs of the selector are set on the GUI.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `slug` is empty, contains a `/`, is `index.html`, or if a GUI
+    /// with the same slug has already been added.
+    pub fn add<G>(&mut self, slug: impl Into<String>, label: impl Into<String>, gui: G) -> &mut Self
+    where
+        G: OpenApiGui + Debug + Send + Sync + 'static,
+    {
+        let slug = slug.into();
+        assert!(
+            !slug.is_empty() && !slug.contains('/') && !is_index_path(&slug),
+            "invalid GUI slug: {slug:?}"
+        );
+        assert!(
+            self.tab(&slug).is_none(),
+            "GUI with slug {slug:?} has already been added"
+        );
+
+        let mut gui: Box<dyn ErasedGui> = Box::new(gui);
+        gui.set_title(self.title.clone());
+        if !self.spec_urls.is_empty() {
+            gui.set_spec_urls(self.spec_urls.clone());
+        }
+        self.tabs.push(Tab {
+            slug,
+            label: label.into(),
+            gui,
+        });
+        self
+    }
+
+    /// Looks up the file that should be served under the given path.
+    ///
+    /// The path is relative to the location the selector is exposed at. An
+    /// empty path (or `index.html`) resolves to the page generated by
+    /// [`GuiSelector::serve`]. Paths starting with the slug of one of the GUIs
+    /// are passed on to [`OpenApiGui::file`] of that GUI, with the slug
+    /// removed. Leading slashes are ignored.
+    ///
+    /// # Returns
+    ///
+    /// The file to be served, or `None` if there is no file under given path.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the index page of the GUI could not be rendered.
+    pub fn file(
+        &self,
+        path: &str,
+    ) -> Result<Option<GuiFile<'static>>, Box<dyn Error + Send + Sync>> {
+        if is_index_path(path) {
+            return Ok(Some(GuiFile::index(self.serve())));
+        }
+
+        let path = path.trim_start_matches('/');
+        let Some((slug, gui_path)) = path.split_once('/') else {
+            return Ok(None);
+        };
+        match self.tab(slug) {
+            Some(tab) => tab.gui.file(gui_path),
+            None => Ok(None),
+        }
+    }
+
+    fn tab(&self, slug: &str) -> Option<&Tab> {
+        self.tabs.iter().find(|tab| tab.slug == slug)
+    }
+
+    /// Generates the HTML for the index page.
+    ///
+    /// The page consists of a tab for each GUI and a frame displaying the
+    /// selected GUI. The selected tab is stored in the URL fragment (e.g.
+    /// `#redoc`), so that links to a specific GUI can be shared.
+    #[must_use]
+    pub fn serve(&self) -> String {
+        let title = escape_html(&self.title);
+        let mut tabs = String::new();
+        for tab in &self.tabs {
+            let slug = escape_html(&tab.slug);
+            let label = escape_html(&tab.label);
+            write!(
+                tabs,
+                "\n    <a href=\"./{slug}/\" target=\"gui\" data-slug=\"{slug}\">{label}</a>"
+            )
+            .expect("writing to a String should not fail");
+        }
+
+        format!(
+            r##"<!DOCTYPE html>
+<html lang="en">
+<head>
+    <meta charset="UTF-8">
+    <meta name="viewport" content="width=device-width, initial-scale=1">
+    <title>{title}</title>
+    <style>
+        html, body {{
+            height: 100%;
+            margin: 0;
+        }}
+        body {{
+            display: flex;
+            flex-direction: column;
+            font-family: sans-serif;
+        }}
+        nav {{
+            display: flex;
+            gap: 4px;
+            padding: 8px 8px 0;
+            background: #1b1b1b;
+        }}
+        nav a {{
+            padding: 8px 16px;
+            border-radius: 4px 4px 0 0;
+            color: #ddd;
+            text-decoration: none;
+        }}
+        nav a[aria-current] {{
+            background: #fff;
+            color: #1b1b1b;
+        }}
+        iframe {{
+            flex: 1;
+            width: 100%;
+            border: 0;
+        }}
+    </style>
+</head>
+<body>
+<nav>{tabs}
+</nav>
+<iframe name="gui" title="{title}"></iframe>
+<script>
+    const tabs = document.querySelectorAll("nav a");
+    const frame = document.querySelector("iframe");
+
+    function select(tab) {{
+        tabs.forEach((other) => other.removeAttribute("aria-current"));
+        tab.setAttribute("aria-current", "page");
+        frame.src = tab.getAttribute("href");
+        history.replaceState(null, "", "#" + tab.dataset.slug);
+    }}
+
+    tabs.forEach((tab) => tab.addEventListener("click", (event) => {{
+        event.preventDefault();
+        select(tab);
+    }}));
+
+    const selected = Array.from(tabs).find((tab) => "#" + tab.dataset.slug === location.hash);
+    if (selected || tabs.length > 0) {{
+        select(selected || tabs[0]);
+    }}
+</script>
+</body>
+</html>
+"##
+        )
+    }
+}
+
+/// Object-safe subset of [`OpenApiGui`], allowing GUIs with different static
+/// file types to be stored together.
+trait ErasedGui: Debug + Send + Sync {
+    fn set_title(&mut self, title: Cow<'static, str>);
+
+    fn set_spec_urls(&mut self, urls: Vec<SpecUrl>);
+
+    fn file(&self, path: &str) -> Result<Option<GuiFile<'static>>, Box<dyn Error + Send + Sync>>;
+}
+
+impl<G: OpenApiGui + Debug + Send + Sync> ErasedGui for G {
+    fn set_title(&mut self, title: Cow<'static, str>) {
+        OpenApiGui::set_title(self, title);
+    }
+
+    fn set_spec_urls(&mut self, urls: Vec<SpecUrl>) {
+        OpenApiGui::set_spec_urls(self, urls);
+    }
+
+    fn file(&self, path: &str) -> Result<Option<GuiFile<'static>>, Box<dyn Error + Send + Sync>> {
+        OpenApiGui::file(self, path)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{FilePaths, StaticFile};
+
+    #[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+    struct TestFile;
+
+    impl StaticFile for TestFile {
+        fn all() -> &'static [Self] {
+            &[TestFile]
+        }
+
+        fn file_name(&self) -> &'static str {
+            "app.js"
+        }
+
+        fn content_type(&self) -> &'static str {
+            "text/javascript; charset=utf-8"
+        }
+
+        fn bytes(&self) -> &'static [u8] {
+            b"console.log(1);"
+        }
+    }
+
+    #[derive(Debug)]
+    struct TestGui {
+        name: &'static str,
+        title: Cow<'static, str>,
+        spec_urls: Vec<SpecUrl>,
+        file_paths: FilePaths<TestFile>,
+    }
+
+    impl TestGui {
+        fn new(name: &'static str) -> Self {
+            Self {
+                name,
+                title: Cow::Borrowed(""),
+                spec_urls: Vec::new(),
+                file_paths: FilePaths::new(),
+            }
+        }
+    }
+
+    impl OpenApiGui for TestGui {
+        type StaticFile = TestFile;
+
+        fn set_title(&mut self, title: Cow<'static, str>) {
+            self.title = title;
+        }
+
+        fn set_spec_urls(&mut self, urls: Vec<SpecUrl>) {
+            self.spec_urls = urls;
+        }
+
+        fn file_paths(&self) -> &FilePaths<TestFile> {
+            &self.file_paths
+        }
+
+        fn file_paths_mut(&mut self) -> &mut FilePaths<TestFile> {
+            &mut self.file_paths
+        }
+
+        fn render_index(&self) -> Result<String, Box<dyn Error + Send + Sync>> {
+            let urls: Vec<_> = self.spec_urls.iter().map(|url| url.url.as_str()).collect();
+            Ok(format!("{} {} {}", self.name, self.title, urls.join(",")))
+        }
+    }
+
+    fn file_content(selector: &GuiSelector, path: &str) -> Option<String> {
+        selector
+            .file(path)
+            .unwrap()
+            .map(|file| String::from_utf8(file.bytes.into_owned()).unwrap())
+    }
+
+    #[test]
+    fn serve_tabs() {
+        let mut selector = GuiSelector::new();
+        selector
+            .title("<My API>")
+            .add("swagger", "Swagger UI", TestGui::new("swagger"))
+            .add("redoc", "ReDoc & more", TestGui::new("redoc"));
+
+        let html = selector.serve();
+        assert!(html.contains("<title>&lt;My API&gt;</title>"));
+        assert!(
+            html.contains(
+                r#"<a href="./swagger/" target="gui" data-slug="swagger">Swagger UI</a>"#
+            )
+        );
+        assert!(
+            html.contains(
+                r#"<a href="./redoc/" target="gui" data-slug="redoc">ReDoc &amp; more</a>"#
+            )
+        );
+        assert_eq!(file_content(&selector, "/index.html"), Some(html));
+    }
+
+    #[test]
+    fn file_routes_to_guis() {
+        let mut selector = GuiSelector::new();
+        selector
+            .spec_urls(vec![SpecUrl::new("/openapi.json")])
+            .add("swagger", "Swagger UI", TestGui::new("swagger"))
+            .add("redoc", "ReDoc", TestGui::new("redoc"))
+            .title("My API");
+
+        assert_eq!(
+            file_content(&selector, "/swagger/"),
+            Some("swagger My API /openapi.json".to_string())
+        );
+        assert_eq!(
+            file_content(&selector, "redoc/index.html"),
+            Some("redoc My API /openapi.json".to_string())
+        );
+        assert_eq!(
+            file_content(&selector, "/redoc/app.js"),
+            Some("console.log(1);".to_string())
+        );
+        assert_eq!(file_content(&selector, "/redoc"), None);
+        assert_eq!(file_content(&selector, "/scalar/"), None);
+        assert_eq!(file_content(&selector, "/app.js"), None);
+    }
+
+    #[test]
+    fn spec_urls_are_propagated() {
+        let mut selector = GuiSelector::new();
+        selector
+            .add("swagger", "Swagger UI", TestGui::new("swagger"))
+            .spec_urls(vec![SpecUrl::new("/v1.json"), SpecUrl::new("/v2.json")]);
+
+        assert_eq!(
+            file_content(&selector, "/swagger/"),
+            Some("swagger API Documentation /v1.json,/v2.json".to_string())
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "has already been added")]
+    fn add_duplicate_slug() {
+        GuiSelector::new()
+            .add("swagger", "Swagger UI", TestGui::new("swagger"))
+            .add("swagger", "Swagger UI", TestGui::new("swagger"));
+    }
+
+    #[test]
+    #[should_panic(expected = "invalid GUI slug")]
+    fn add_invalid_slug() {
+        GuiSelector::new().add("docs/swagger", "Swagger UI", TestGui::new("swagger"));
+    }
+}
@@ -0,0 +1,754 @@
+//! This crate implements necessary boilerplate code to serve [RapiDoc] via web
+//! server. It provides a simple API to configure RapiDoc and serve it via a
+//! web server. The crate is deliberately kept simple and does not implement
+//! any web server specific code. It is up to the user to implement the web
+//! server specific code for the web framework of choice.
+//!
+//! It does not download RapiDoc from the internet, but rather includes the
+//! necessary static files in the crate. This reduces the number of build
+//! dependencies and makes it easy to use the crate offline.
+//!
+//! RapiDoc is configured entirely through the attributes of the `<rapi-doc>`
+//! element, which are modeled by [`Config`].
+//!
+//! # Attribution
+//!
+//! [RapiDoc] included in this crate is licensed under MIT.
+//!
+//! [RapiDoc]: https://rapidocweb.com/
+
+#![warn(missing_docs, rustdoc::missing_crate_level_docs)]
+
+use std::borrow::Cow;
+use std::error::Error;
+
+use openapi_gui_core::{
+    FilePaths, GuiFile, HtmlAttributes, INDEX_CONTENT_TYPE, OpenApiGui, SpecUrl, StaticFile,
+    escape_html, is_index_path,
+};
+
+/// Entry point for serving RapiDoc and api docs in application. It provides
+/// builder style chainable configuration methods for configuring the api doc
+/// url and the look of the page.
+///
+/// # Examples
+///
+/// ```
+/// # use rapidoc_redist::RapiDoc;
+/// let mut rapidoc = RapiDoc::new();
+/// rapidoc.config().spec_url("/api-docs/openapi.json");
+/// let static_files = RapiDoc::static_files(); // static files that are needed to be served
+/// let html = rapidoc.serve();
+/// ```
+#[non_exhaustive]
+#[derive(Debug, Clone)]
+pub struct RapiDoc {
+    title: Cow<'static, str>,
+    config: Config,
+    file_paths: FilePaths<RapiDocStaticFile>,
+}
+
+impl Default for RapiDoc {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl RapiDoc {
+    /// Create a new [`RapiDoc`] with the default configuration.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use rapidoc_redist::RapiDoc;
+    /// let rapidoc = RapiDoc::new();
+    /// ```
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            title: Cow::Borrowed("RapiDoc"),
+            config: Config::new(),
+            file_paths: FilePaths::new(),
+        }
+    }
+
+    /// Return a mutable reference to the config, allowing to modify it.
+    ///
+    /// This is useful for setting up RapiDoc with custom settings, but also
+    /// for setting up the URL of the OpenAPI spec.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use rapidoc_redist::{RapiDoc, RenderStyle, Theme};
+    /// let mut rapidoc = RapiDoc::new();
+    /// rapidoc
+    ///     .config()
+    ///     .spec_url("/api-docs/openapi.json")
+    ///     .render_style(RenderStyle::Focused)
+    ///     .theme(Theme::Dark);
+    /// ```
+    pub fn config(&mut self) -> &mut Config {
+        &mut self.config
+    }
+
+    /// Sets the title for the RapiDoc page.
+    ///
+    /// The title will be displayed in the browser tab.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use rapidoc_redist::RapiDoc;
+    /// let mut rapidoc = RapiDoc::new();
+    /// rapidoc.title("My API Documentation");
+    /// ```
+    pub fn title(&mut self, title: impl Into<Cow<'static, str>>) -> &mut Self {
+        self.title = title.into();
+        self
+    }
+
+    /// Returns a reference to all static files required by RapiDoc.
+    ///
+    /// Each file is paired with its corresponding [`RapiDocStaticFile`] enum
+    /// variant for identification.
+    ///
+    /// # Returns
+    ///
+    /// A static slice of tuples containing the file identifier and its raw
+    /// content.
+    #[must_use]
+    pub fn static_files() -> &'static [(RapiDocStaticFile, &'static [u8])] {
+        &[
+            (
+                RapiDocStaticFile::Js,
+                include_bytes!("../res/rapidoc-min.js"),
+            ),
+            (
+                RapiDocStaticFile::OAuthReceiver,
+                include_bytes!("../res/oauth-receiver.html"),
+            ),
+        ]
+    }
+
+    /// Overrides the path for a specific static file.
+    ///
+    /// This method allows customizing the URL paths where static files are
+    /// served from. This is useful when integrating with web frameworks
+    /// that have specific routing requirements or when serving files from a
+    /// CDN or different location.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use rapidoc_redist::{RapiDoc, RapiDocStaticFile};
+    /// let mut rapidoc = RapiDoc::new();
+    /// rapidoc.override_file_path(RapiDocStaticFile::Js, "/assets/rapidoc.js".to_string());
+    /// ```
+    pub fn override_file_path(&mut self, static_file: RapiDocStaticFile, path: String) {
+        self.file_paths.set(static_file, path);
+    }
+
+    /// Looks up the file that should be served under the given path.
+    ///
+    /// The path is relative to the location RapiDoc is exposed at. An empty
+    /// path (or `index.html`) resolves to the HTML page generated by
+    /// [`RapiDoc::serve`], while the remaining paths are matched against the
+    /// (possibly overridden) relative paths of the static files. Leading
+    /// slashes are ignored.
+    ///
+    /// Static files whose path has been overridden with an absolute path or a
+    /// URL are not served, as the generated page does not reference them
+    /// relative to the RapiDoc location.
+    ///
+    /// # Returns
+    ///
+    /// The file to be served, or `None` if there is no file under given path.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use rapidoc_redist::RapiDoc;
+    /// let mut rapidoc = RapiDoc::new();
+    /// rapidoc.config().spec_url("/api-docs/openapi.json");
+    ///
+    /// let index = rapidoc.file("/").expect("index should be present");
+    /// assert_eq!(index.content_type, "text/html; charset=utf-8");
+    ///
+    /// let js = rapidoc
+    ///     .file("/rapidoc-min.js")
+    ///     .expect("JS should be present");
+    /// assert_eq!(js.content_type, "text/javascript; charset=utf-8");
+    ///
+    /// assert!(rapidoc.file("/does-not-exist.js").is_none());
+    /// ```
+    #[must_use]
+    pub fn file(&self, path: &str) -> Option<RapiDocFile<'static>> {
+        if is_index_path(path) {
+            return Some(GuiFile::index(self.serve()));
+        }
+
+        self.file_paths.find(path).map(GuiFile::from_static_file)
+    }
+
+    /// Generates the HTML for the RapiDoc page.
+    ///
+    /// This method creates a complete HTML document that references the
+    /// RapiDoc JavaScript bundle and renders the `<rapi-doc>` element with the
+    /// attributes specified in the [`Config`] object. All attribute values are
+    /// escaped.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use rapidoc_redist::RapiDoc;
+    /// let mut rapidoc = RapiDoc::new();
+    /// rapidoc.config().spec_url("/api-docs/openapi.json");
+    /// let html = rapidoc.serve();
+    /// assert!(html.contains(r#"spec-url="/api-docs/openapi.json""#));
+    /// ```
+    #[must_use]
+    pub fn serve(&self) -> String {
+        let title = escape_html(&self.title);
+        let js_path = escape_html(self.file_paths.get(RapiDocStaticFile::Js));
+
+        let mut attributes = self.config.attributes();
+        // RapiDoc looks for `oauth-receiver.html` next to the page by default
+        let oauth_receiver_path = self.file_paths.get(RapiDocStaticFile::OAuthReceiver);
+        attributes.text(
+            "oauth-receiver",
+            (oauth_receiver_path != RapiDocStaticFile::OAuthReceiver.default_path())
+                .then_some(oauth_receiver_path),
+        );
+
+        format!(
+            r#"<!DOCTYPE html>
+<html lang="en">
+<head>
+    <meta charset="UTF-8">
+    <meta name="viewport" content="width=device-width, minimum-scale=1, initial-scale=1, user-scalable=yes">
+    <title>{title}</title>
+    <script type="module" src="{js_path}"></script>
+</head>
+<body>
+<rapi-doc{attributes}
+></rapi-doc>
+</body>
+</html>
+"#
+        )
+    }
+}
+
+/// Represents the static files required by RapiDoc.
+#[non_exhaustive]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum RapiDocStaticFile {
+    /// The RapiDoc JavaScript bundle.
+    Js,
+    /// The page RapiDoc redirects to after completing the OAuth2 authorization
+    /// flow.
+    ///
+    /// The page references the JavaScript bundle under its default path, so
+    /// it has to be served next to it. If the path of this file is overridden,
+    /// it is passed to RapiDoc with the `oauth-receiver` attribute.
+    OAuthReceiver,
+}
+
+impl RapiDocStaticFile {
+    /// Returns a slice containing all available RapiDoc static files.
+    #[must_use]
+    pub fn all() -> &'static [Self] {
+        &[RapiDocStaticFile::Js, RapiDocStaticFile::OAuthReceiver]
+    }
+
+    /// Returns the filename for a specific static file.
+    #[must_use]
+    pub fn file_name(&self) -> &'static str {
+        match self {
+            RapiDocStaticFile::Js => "rapidoc-min.js",
+            RapiDocStaticFile::OAuthReceiver => "oauth-receiver.html",
+        }
+    }
+
+    /// Returns the static file with given filename, if there is one.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use rapidoc_redist::RapiDocStaticFile;
+    /// assert_eq!(
+    ///     RapiDocStaticFile::from_file_name("rapidoc-min.js"),
+    ///     Some(RapiDocStaticFile::Js)
+    /// );
+    /// assert_eq!(RapiDocStaticFile::from_file_name("index.html"), None);
+    /// ```
+    #[must_use]
+    pub fn from_file_name(file_name: &str) -> Option<Self> {
+        Self::all()
+            .iter()
+            .copied()
+            .find(|file| file.file_name() == file_name)
+    }
+
+    /// Returns the content type of a specific static file, suitable for use in
+    /// the `Content-Type` header.
+    #[must_use]
+    pub fn content_type(&self) -> &'static str {
+        match self {
+            RapiDocStaticFile::Js => "text/javascript; charset=utf-8",
+            RapiDocStaticFile::OAuthReceiver => INDEX_CONTENT_TYPE,
+        }
+    }
+
+    /// Returns the raw content of a specific static file.
+    ///
+    /// This is the same content as returned by [`RapiDoc::static_files`].
+    #[must_use]
+    #[expect(clippy::missing_panics_doc)]
+    pub fn bytes(&self) -> &'static [u8] {
+        RapiDoc::static_files()
+            .iter()
+            .find(|(file, _)| file == self)
+            .map(|(_, bytes)| *bytes)
+            .expect("all files should be present")
+    }
+}
+
+impl StaticFile for RapiDocStaticFile {
+    fn all() -> &'static [Self] {
+        Self::all()
+    }
+
+    fn file_name(&self) -> &'static str {
+        self.file_name()
+    }
+
+    fn content_type(&self) -> &'static str {
+        self.content_type()
+    }
+
+    fn bytes(&self) -> &'static [u8] {
+        self.bytes()
+    }
+}
+
+impl OpenApiGui for RapiDoc {
+    type StaticFile = RapiDocStaticFile;
+
+    fn set_title(&mut self, title: Cow<'static, str>) {
+        self.title = title;
+    }
+
+    fn set_spec_urls(&mut self, urls: Vec<SpecUrl>) {
+        self.config.spec_url = SpecUrl::primary_of(&urls).map(|url| url.url.clone());
+    }
+
+    fn file_paths(&self) -> &FilePaths<RapiDocStaticFile> {
+        &self.file_paths
+    }
+
+    fn file_paths_mut(&mut self) -> &mut FilePaths<RapiDocStaticFile> {
+        &mut self.file_paths
+    }
+
+    fn render_index(&self) -> Result<String, Box<dyn Error + Send + Sync>> {
+        Ok(self.serve())
+    }
+}
+
+/// Represents servable file of RapiDoc. This is returned by [`RapiDoc::file`]
+/// to serve RapiDoc files via web server.
+pub type RapiDocFile<'a> = GuiFile<'a>;
+
+/// How the operations are rendered.
+#[non_exhaustive]
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum RenderStyle {
+    /// All operations on a single page, with the details in collapsible
+    /// sections.
+    Read,
+    /// All operations on a single page, expanded when clicked.
+    View,
+    /// One operation at a time, selected in the navigation bar.
+    Focused,
+}
+
+impl RenderStyle {
+    fn as_str(self) -> &'static str {
+        match self {
+            RenderStyle::Read => "read",
+            RenderStyle::View => "view",
+            RenderStyle::Focused => "focused",
+        }
+    }
+}
+
+/// Color theme of the page.
+#[non_exhaustive]
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Theme {
+    /// Light theme.
+    Light,
+    /// Dark theme.
+    Dark,
+}
+
+impl Theme {
+    fn as_str(self) -> &'static str {
+        match self {
+            Theme::Light => "light",
+            Theme::Dark => "dark",
+        }
+    }
+}
+
+/// Placement of the request and response sections.
+#[non_exhaustive]
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Layout {
+    /// Request and response side by side.
+    Row,
+    /// Response below the request.
+    Column,
+}
+
+impl Layout {
+    fn as_str(self) -> &'static str {
+        match self {
+            Layout::Row => "row",
+            Layout::Column => "column",
+        }
+    }
+}
+
+/// How the schemas are displayed.
+#[non_exhaustive]
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum SchemaStyle {
+    /// As a tree.
+    Tree,
+    /// As a table.
+    Table,
+}
+
+impl SchemaStyle {
+    fn as_str(self) -> &'static str {
+        match self {
+            SchemaStyle::Tree => "tree",
+            SchemaStyle::Table => "table",
+        }
+    }
+}
+
+/// Where the API key is sent.
+#[non_exhaustive]
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum ApiKeyLocation {
+    /// In a request header.
+    Header,
+    /// In a query parameter.
+    Query,
+}
+
+impl ApiKeyLocation {
+    fn as_str(self) -> &'static str {
+        match self {
+            ApiKeyLocation::Header => "header",
+            ApiKeyLocation::Query => "query",
+        }
+    }
+}
+
+/// An API key RapiDoc sends along with the requests made with "Try".
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct ApiKey {
+    name: String,
+    location: ApiKeyLocation,
+    value: String,
+}
+
+/// Object used to alter RapiDoc settings. Every setting maps to an attribute of
+/// the `<rapi-doc>` element.
+///
+/// Only the attributes that were set explicitly are rendered, so RapiDoc's own
+/// defaults apply to everything else.
+///
+/// # Examples
+///
+/// ```
+/// # use rapidoc_redist::{ApiKeyLocation, Config, RenderStyle};
+/// let mut config = Config::new();
+/// config
+///     .spec_url("/api-docs/openapi.json")
+///     .render_style(RenderStyle::Read)
+///     .allow_try(true)
+///     .api_key("X-Api-Key", ApiKeyLocation::Header, "secret");
+/// ```
+#[non_exhaustive]
+#[derive(Debug, Default, Clone)]
+pub struct Config {
+    spec_url: Option<String>,
+    render_style: Option<RenderStyle>,
+    theme: Option<Theme>,
+    layout: Option<Layout>,
+    schema_style: Option<SchemaStyle>,
+    heading_text: Option<String>,
+    show_header: Option<bool>,
+    primary_color: Option<String>,
+    allow_try: Option<bool>,
+    allow_authentication: Option<bool>,
+    allow_search: Option<bool>,
+    allow_spec_file_download: Option<bool>,
+    allow_server_selection: Option<bool>,
+    server_url: Option<String>,
+    default_api_server: Option<String>,
+    api_key: Option<ApiKey>,
+}
+
+impl Config {
+    /// Constructs a new [`Config`] with RapiDoc's default settings.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the URL of the OpenAPI spec to be rendered (`spec-url`).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use rapidoc_redist::Config;
+    /// let mut config = Config::new();
+    /// config.spec_url("/api-docs/openapi.json");
+    /// ```
+    pub fn spec_url<S: Into<String>>(&mut self, spec_url: S) -> &mut Self {
+        self.spec_url = Some(spec_url.into());
+        self
+    }
+
+    /// Sets how the operations are rendered (`render-style`). Default is
+    /// [`RenderStyle::View`].
+    pub fn render_style(&mut self, render_style: RenderStyle) -> &mut Self {
+        self.render_style = Some(render_style);
+        self
+    }
+
+    /// Sets the color theme of the page (`theme`). Default is
+    /// [`Theme::Light`].
+    pub fn theme(&mut self, theme: Theme) -> &mut Self {
+        self.theme = Some(theme);
+        self
+    }
+
+    /// Sets the placement of the request and response sections (`layout`).
+    /// Default is [`Layout::Row`].
+    pub fn layout(&mut self, layout: Layout) -> &mut Self {
+        self.layout = Some(layout);
+        self
+    }
+
+    /// Sets how the schemas are displayed (`schema-style`). Default is
+    /// [`SchemaStyle::Tree`].
+    pub fn schema_style(&mut self, schema_style: SchemaStyle) -> &mut Self {
+        self.schema_style = Some(schema_style);
+        self
+    }
+
+    /// Sets the text shown in the header (`heading-text`).
+    pub fn heading_text<S: Into<String>>(&mut self, heading_text: S) -> &mut Self {
+        self.heading_text = Some(heading_text.into());
+        self
+    }
+
+    /// Sets whether the header is shown (`show-header`). Default is `true`.
+    pub fn show_header(&mut self, show_header: bool) -> &mut Self {
+        self.show_header = Some(show_header);
+        self
+    }
+
+    /// Sets the primary color of the page (`primary-color`), e.g. `"#ff5500"`.
+    pub fn primary_color<S: Into<String>>(&mut self, primary_color: S) -> &mut Self {
+        self.primary_color = Some(primary_color.into());
+        self
+    }
+
+    /// Sets whether the requests can be tried out from the page (`allow-try`).
+    /// Default is `true`.
+    pub fn allow_try(&mut self, allow_try: bool) -> &mut Self {
+        self.allow_try = Some(allow_try);
+        self
+    }
+
+    /// Sets whether the authentication section is shown
+    /// (`allow-authentication`). Default is `true`.
+    pub fn allow_authentication(&mut self, allow_authentication: bool) -> &mut Self {
+        self.allow_authentication = Some(allow_authentication);
+        self
+    }
+
+    /// Sets whether search is enabled (`allow-search`). Default is `true`.
+    pub fn allow_search(&mut self, allow_search: bool) -> &mut Self {
+        self.allow_search = Some(allow_search);
+        self
+    }
+
+    /// Sets whether the spec can be downloaded from the page
+    /// (`allow-spec-file-download`). Default is `false`.
+    pub fn allow_spec_file_download(&mut self, allow_spec_file_download: bool) -> &mut Self {
+        self.allow_spec_file_download = Some(allow_spec_file_download);
+        self
+    }
+
+    /// Sets whether the user can select the server the requests are sent to
+    /// (`allow-server-selection`). Default is `true`.
+    pub fn allow_server_selection(&mut self, allow_server_selection: bool) -> &mut Self {
+        self.allow_server_selection = Some(allow_server_selection);
+        self
+    }
+
+    /// Sets an additional server URL the requests can be sent to
+    /// (`server-url`).
+    pub fn server_url<S: Into<String>>(&mut self, server_url: S) -> &mut Self {
+        self.server_url = Some(server_url.into());
+        self
+    }
+
+    /// Sets the server URL selected by default (`default-api-server`).
+    pub fn default_api_server<S: Into<String>>(&mut self, default_api_server: S) -> &mut Self {
+        self.default_api_server = Some(default_api_server.into());
+        self
+    }
+
+    /// Sets an API key sent along with the requests made from the page
+    /// (`api-key-name`, `api-key-location` and `api-key-value`).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use rapidoc_redist::{ApiKeyLocation, Config};
+    /// let mut config = Config::new();
+    /// config.api_key("api_key", ApiKeyLocation::Query, "demo");
+    /// ```
+    pub fn api_key<N: Into<String>, V: Into<String>>(
+        &mut self,
+        name: N,
+        location: ApiKeyLocation,
+        value: V,
+    ) -> &mut Self {
+        self.api_key = Some(ApiKey {
+            name: name.into(),
+            location,
+            value: value.into(),
+        });
+        self
+    }
+
+    /// Returns the `<rapi-doc>` attributes for the settings that were set.
+    fn attributes(&self) -> HtmlAttributes<'_> {
+        let mut attributes = HtmlAttributes::new();
+        attributes
+            .text("spec-url", self.spec_url.as_deref())
+            .text("render-style", self.render_style.map(RenderStyle::as_str))
+            .text("theme", self.theme.map(Theme::as_str))
+            .text("layout", self.layout.map(Layout::as_str))
+            .text("schema-style", self.schema_style.map(SchemaStyle::as_str))
+            .text("heading-text", self.heading_text.as_deref())
+            .flag("show-header", self.show_header)
+            .text("primary-color", self.primary_color.as_deref())
+            .flag("allow-try", self.allow_try)
+            .flag("allow-authentication", self.allow_authentication)
+            .flag("allow-search", self.allow_search)
+            .flag("allow-spec-file-download", self.allow_spec_file_download)
+            .flag("allow-server-selection", self.allow_server_selection)
+            .text("server-url", self.server_url.as_deref())
+            .text("default-api-server", self.default_api_server.as_deref());
+        if let Some(api_key) = &self.api_key {
+            attributes
+                .text("api-key-name", Some(&api_key.name))
+                .text("api-key-location", Some(api_key.location.as_str()))
+                .text("api-key-value", Some(&api_key.value));
+        }
+
+        attributes
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serve_default() {
+        let html = RapiDoc::new().serve();
+
+        assert!(html.contains("<title>RapiDoc</title>"));
+        assert!(html.contains(r#"<script type="module" src="./rapidoc-min.js"></script>"#));
+        assert!(html.contains("<rapi-doc\n></rapi-doc>"));
+    }
+
+    #[test]
+    fn serve_attributes() {
+        let mut rapidoc = RapiDoc::new();
+        rapidoc
+            .config()
+            .spec_url("/openapi.json")
+            .render_style(RenderStyle::Focused)
+            .theme(Theme::Dark)
+            .allow_try(false)
+            .allow_server_selection(true)
+            .default_api_server("https://api.example.com")
+            .api_key("X-Api-Key", ApiKeyLocation::Header, "secret");
+
+        let html = rapidoc.serve();
+
+        assert!(html.contains(
+            r#"<rapi-doc
+    spec-url="/openapi.json"
+    render-style="focused"
+    theme="dark"
+    allow-try="false"
+    allow-server-selection="true"
+    default-api-server="https://api.example.com"
+    api-key-name="X-Api-Key"
+    api-key-location="header"
+    api-key-value="secret"
+></rapi-doc>"#
+        ));
+    }
+
+    #[test]
+    fn serve_escapes_attributes() {
+        let mut rapidoc = RapiDoc::new();
+        rapidoc.title("</title>");
+        rapidoc
+            .config()
+            .spec_url("/openapi.json?a=1&b=\"2\"")
+            .heading_text("<script>alert('x')</script>");
+
+        let html = rapidoc.serve();
+
+        assert!(html.contains("<title>&lt;/title&gt;</title>"));
+        assert!(html.contains(r#"spec-url="/openapi.json?a=1&amp;b=&quot;2&quot;""#));
+        assert!(html.contains(r#"heading-text="&lt;script&gt;alert(&#39;x&#39;)&lt;/script&gt;""#));
+    }
+
+    #[test]
+    fn oauth_receiver_override() {
+        let mut rapidoc = RapiDoc::new();
+        assert!(!rapidoc.serve().contains("oauth-receiver"));
+        assert!(rapidoc.file("/oauth-receiver.html").is_some());
+
+        rapidoc.override_file_path(
+            RapiDocStaticFile::OAuthReceiver,
+            "/auth/receiver.html".to_string(),
+        );
+
+        assert!(
+            rapidoc
+                .serve()
+                .contains(r#"oauth-receiver="/auth/receiver.html""#)
+        );
+        assert!(rapidoc.file("/oauth-receiver.html").is_none());
+    }
+}
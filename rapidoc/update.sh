@@ -0,0 +1,16 @@
+#!/bin/bash
+
+set -e -o pipefail
+
+if [ -z "$1" ]; then
+    echo "USAGE: $0 <version>"
+    echo "Example: $0 v9.3.4"
+    exit 1
+fi
+
+VERSION="$1"
+
+curl -o res/LICENSE https://raw.githubusercontent.com/rapi-doc/RapiDoc/refs/tags/$VERSION/LICENSE.txt
+curl -o res/rapidoc-min.js https://cdn.jsdelivr.net/npm/rapidoc@${VERSION#v}/dist/rapidoc-min.js
+
+sed -i "s/<!-- version -->.*$/<!-- version -->The version of RapiDoc included in this crate is $VERSION./" README.md
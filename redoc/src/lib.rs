@@ -0,0 +1,706 @@
+//! This crate implements necessary boilerplate code to serve [ReDoc] via web
+//! server. It provides a simple API to configure ReDoc and serve it via a web
+//! server. The crate is deliberately kept simple and does not implement any
+//! web server specific code. It is up to the user to implement the web server
+//! specific code for the web framework of choice.
+//!
+//! It does not download ReDoc from the internet, but rather includes the
+//! necessary static files in the crate. This reduces the number of build
+//! dependencies and makes it easy to use the crate offline.
+//!
+//! # Attribution
+//!
+//! [ReDoc] included in this crate is licensed under MIT.
+//!
+//! [ReDoc]: https://github.com/Redocly/redoc
+
+#![warn(missing_docs, rustdoc::missing_crate_level_docs)]
+
+use std::borrow::Cow;
+use std::error::Error;
+
+use openapi_gui_core::{FilePaths, GuiFile, OpenApiGui, SpecUrl, StaticFile, escape_html};
+use serde::{Serialize, Serializer};
+
+/// Entry point for serving ReDoc and api docs in application. It provides
+/// builder style chainable configuration methods for configuring the api doc
+/// url and the look of the page.
+///
+/// # Examples
+///
+/// ```
+/// # use redoc_redist::Redoc;
+/// let mut redoc = Redoc::new();
+/// redoc.config().spec_url("/api-docs/openapi.json");
+/// let static_files = Redoc::static_files(); // static files that are needed to be served
+/// let html = redoc.serve()?;
+/// # Ok::<(), Box<dyn std::error::Error + Send + Sync>>(())
+/// ```
+#[non_exhaustive]
+#[derive(Debug, Clone)]
+pub struct Redoc {
+    title: Cow<'static, str>,
+    config: RedocConfig,
+    file_paths: FilePaths<RedocStaticFile>,
+}
+
+impl Default for Redoc {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Redoc {
+    /// Create a new [`Redoc`] with the default configuration.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use redoc_redist::Redoc;
+    /// let redoc = Redoc::new();
+    /// ```
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            title: Cow::Borrowed("ReDoc"),
+            config: RedocConfig::new(),
+            file_paths: FilePaths::new(),
+        }
+    }
+
+    /// Return a mutable reference to the config, allowing to modify it.
+    ///
+    /// This is useful for setting up ReDoc with custom settings, but also for
+    /// setting up the URL of the OpenAPI spec.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use redoc_redist::Redoc;
+    /// let mut redoc = Redoc::new();
+    /// redoc
+    ///     .config()
+    ///     .spec_url("/api-docs/openapi.json")
+    ///     .hide_download_button(true);
+    /// ```
+    pub fn config(&mut self) -> &mut RedocConfig {
+        &mut self.config
+    }
+
+    /// Sets the title for the ReDoc page.
+    ///
+    /// The title will be displayed in the browser tab.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use redoc_redist::Redoc;
+    /// let mut redoc = Redoc::new();
+    /// redoc.title("My API Documentation");
+    /// ```
+    pub fn title(&mut self, title: impl Into<Cow<'static, str>>) -> &mut Self {
+        self.title = title.into();
+        self
+    }
+
+    /// Returns a reference to all static files required by ReDoc.
+    ///
+    /// Each file is paired with its corresponding [`RedocStaticFile`] enum
+    /// variant for identification.
+    ///
+    /// # Returns
+    ///
+    /// A static slice of tuples containing the file identifier and its raw
+    /// content.
+    #[must_use]
+    pub fn static_files() -> &'static [(RedocStaticFile, &'static [u8])] {
+        &[(
+            RedocStaticFile::Js,
+            include_bytes!("../res/redoc.standalone.js"),
+        )]
+    }
+
+    /// Overrides the path for a specific static file.
+    ///
+    /// This method allows customizing the URL paths where static files are
+    /// served from. This is useful when integrating with web frameworks
+    /// that have specific routing requirements or when serving files from a
+    /// CDN or different location.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use redoc_redist::{Redoc, RedocStaticFile};
+    /// let mut redoc = Redoc::new();
+    /// redoc.override_file_path(RedocStaticFile::Js, "/assets/redoc.js".to_string());
+    /// ```
+    pub fn override_file_path(&mut self, static_file: RedocStaticFile, path: String) {
+        self.file_paths.set(static_file, path);
+    }
+
+    /// Looks up the file that should be served under the given path.
+    ///
+    /// The path is relative to the location ReDoc is exposed at. An empty path
+    /// (or `index.html`) resolves to the HTML page generated by
+    /// [`Redoc::serve`], while the remaining paths are matched against the
+    /// (possibly overridden) relative paths of the static files. Leading
+    /// slashes are ignored.
+    ///
+    /// Static files whose path has been overridden with an absolute path or a
+    /// URL are not served, as the generated page does not reference them
+    /// relative to the ReDoc location.
+    ///
+    /// # Returns
+    ///
+    /// The file to be served, or `None` if there is no file under given path.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the ReDoc config fails to be serialized.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use redoc_redist::Redoc;
+    /// let mut redoc = Redoc::new();
+    /// redoc.config().spec_url("/api-docs/openapi.json");
+    ///
+    /// let index = redoc.file("/")?.expect("index should be present");
+    /// assert_eq!(index.content_type, "text/html; charset=utf-8");
+    ///
+    /// let js = redoc
+    ///     .file("/redoc.standalone.js")?
+    ///     .expect("JS should be present");
+    /// assert_eq!(js.content_type, "text/javascript; charset=utf-8");
+    ///
+    /// assert!(redoc.file("/does-not-exist.js")?.is_none());
+    /// # Ok::<(), Box<dyn std::error::Error + Send + Sync>>(())
+    /// ```
+    pub fn file(
+        &self,
+        path: &str,
+    ) -> Result<Option<RedocFile<'static>>, Box<dyn Error + Send + Sync>> {
+        OpenApiGui::file(self, path)
+    }
+
+    /// Generates the HTML for the ReDoc page.
+    ///
+    /// This method creates a complete HTML document that references the ReDoc
+    /// JavaScript bundle and initializes it with the settings specified in
+    /// the [`RedocConfig`] object.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the ReDoc config fails to be serialized.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use redoc_redist::Redoc;
+    /// let mut redoc = Redoc::new();
+    /// redoc.config().spec_url("/api-docs/openapi.json");
+    /// let html = redoc.serve()?;
+    /// # Ok::<(), Box<dyn std::error::Error + Send + Sync>>(())
+    /// ```
+    pub fn serve(&self) -> Result<String, Box<dyn Error + Send + Sync>> {
+        let title = escape_html(&self.title);
+        let js_path = escape_html(self.file_paths.get(RedocStaticFile::Js));
+        // neither the spec URL nor the options may end the script
+        let spec_url = serde_json::to_string(&self.config.spec_url)?.replace("</", r"<\/");
+        let config = serde_json::to_string(&self.config)?.replace("</", r"<\/");
+
+        Ok(format!(
+            r#"<!DOCTYPE html>
+<html lang="en">
+<head>
+    <meta charset="UTF-8">
+    <meta name="viewport" content="width=device-width, initial-scale=1">
+    <title>{title}</title>
+    <style>
+        body {{
+            margin: 0;
+            padding: 0;
+        }}
+    </style>
+</head>
+<body>
+<div id="redoc-container"></div>
+<script src="{js_path}" charset="UTF-8"></script>
+<script>
+    Redoc.init({spec_url}, {config}, document.getElementById("redoc-container"));
+</script>
+</body>
+</html>
+"#
+        ))
+    }
+}
+
+/// Represents the static files required by ReDoc.
+#[non_exhaustive]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum RedocStaticFile {
+    /// The standalone ReDoc JavaScript bundle.
+    Js,
+}
+
+impl RedocStaticFile {
+    /// Returns a slice containing all available ReDoc static files.
+    #[must_use]
+    pub fn all() -> &'static [Self] {
+        &[RedocStaticFile::Js]
+    }
+
+    /// Returns the filename for a specific static file.
+    #[must_use]
+    pub fn file_name(&self) -> &'static str {
+        match self {
+            RedocStaticFile::Js => "redoc.standalone.js",
+        }
+    }
+
+    /// Returns the static file with given filename, if there is one.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use redoc_redist::RedocStaticFile;
+    /// assert_eq!(
+    ///     RedocStaticFile::from_file_name("redoc.standalone.js"),
+    ///     Some(RedocStaticFile::Js)
+    /// );
+    /// assert_eq!(RedocStaticFile::from_file_name("index.html"), None);
+    /// ```
+    #[must_use]
+    pub fn from_file_name(file_name: &str) -> Option<Self> {
+        Self::all()
+            .iter()
+            .copied()
+            .find(|file| file.file_name() == file_name)
+    }
+
+    /// Returns the content type of a specific static file, suitable for use in
+    /// the `Content-Type` header.
+    #[must_use]
+    pub fn content_type(&self) -> &'static str {
+        match self {
+            RedocStaticFile::Js => "text/javascript; charset=utf-8",
+        }
+    }
+
+    /// Returns the raw content of a specific static file.
+    ///
+    /// This is the same content as returned by [`Redoc::static_files`].
+    #[must_use]
+    #[expect(clippy::missing_panics_doc)]
+    pub fn bytes(&self) -> &'static [u8] {
+        Redoc::static_files()
+            .iter()
+            .find(|(file, _)| file == self)
+            .map(|(_, bytes)| *bytes)
+            .expect("all files should be present")
+    }
+}
+
+impl StaticFile for RedocStaticFile {
+    fn all() -> &'static [Self] {
+        Self::all()
+    }
+
+    fn file_name(&self) -> &'static str {
+        self.file_name()
+    }
+
+    fn content_type(&self) -> &'static str {
+        self.content_type()
+    }
+
+    fn bytes(&self) -> &'static [u8] {
+        self.bytes()
+    }
+}
+
+impl OpenApiGui for Redoc {
+    type StaticFile = RedocStaticFile;
+
+    fn set_title(&mut self, title: Cow<'static, str>) {
+        self.title = title;
+    }
+
+    fn set_spec_urls(&mut self, urls: Vec<SpecUrl>) {
+        if let Some(url) = SpecUrl::primary_of(&urls) {
+            self.config.spec_url.clone_from(&url.url);
+        }
+    }
+
+    fn file_paths(&self) -> &FilePaths<RedocStaticFile> {
+        &self.file_paths
+    }
+
+    fn file_paths_mut(&mut self) -> &mut FilePaths<RedocStaticFile> {
+        &mut self.file_paths
+    }
+
+    fn render_index(&self) -> Result<String, Box<dyn Error + Send + Sync>> {
+        self.serve()
+    }
+}
+
+/// Represents servable file of ReDoc. This is returned by [`Redoc::file`] to
+/// serve ReDoc files via web server.
+pub type RedocFile<'a> = GuiFile<'a>;
+
+/// The responses expanded by default.
+#[non_exhaustive]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ExpandResponses {
+    /// Expand all responses.
+    All,
+    /// Expand the responses with given status codes, e.g. `"200"` or `"201"`.
+    StatusCodes(Vec<String>),
+}
+
+impl Serialize for ExpandResponses {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            ExpandResponses::All => serializer.serialize_str("all"),
+            ExpandResponses::StatusCodes(codes) => serializer.serialize_str(&codes.join(",")),
+        }
+    }
+}
+
+/// How deep the JSON samples are expanded by default.
+#[non_exhaustive]
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum ExpandLevel {
+    /// Expand given number of levels.
+    Levels(u32),
+    /// Expand all levels.
+    All,
+}
+
+impl Serialize for ExpandLevel {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            ExpandLevel::Levels(levels) => serializer.serialize_u32(*levels),
+            ExpandLevel::All => serializer.serialize_str("all"),
+        }
+    }
+}
+
+/// Object used to alter ReDoc settings.
+///
+/// Only the options that were set explicitly are passed to ReDoc, so its own
+/// defaults apply to everything else.
+///
+/// # Examples
+///
+/// ```
+/// # use redoc_redist::{ExpandLevel, ExpandResponses, RedocConfig};
+/// let mut config = RedocConfig::new();
+/// config
+///     .spec_url("/api-docs/openapi.json")
+///     .hide_download_button(true)
+///     .expand_responses(ExpandResponses::StatusCodes(vec!["200".to_string()]))
+///     .json_sample_expand_level(ExpandLevel::All);
+/// ```
+#[non_exhaustive]
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RedocConfig {
+    /// URL of the OpenAPI spec. This is passed to ReDoc separately from the
+    /// options.
+    #[serde(skip)]
+    spec_url: String,
+
+    /// Whether to hide the spec download button.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    hide_download_button: Option<bool>,
+
+    /// The responses expanded by default.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    expand_responses: Option<ExpandResponses>,
+
+    /// How deep the JSON samples are expanded by default.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    json_sample_expand_level: Option<ExpandLevel>,
+
+    /// ReDoc theme object.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    theme: Option<serde_json::Value>,
+
+    /// Offset from the top of the page to scroll to.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    scroll_y_offset: Option<u32>,
+
+    /// Whether to hide the protocol and hostname in operation paths.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    hide_hostname: Option<bool>,
+
+    /// Whether to show required properties first.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    required_props_first: Option<bool>,
+
+    /// Whether to sort properties alphabetically.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    sort_props_alphabetically: Option<bool>,
+
+    /// Whether to show operation paths in the middle panel.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    path_in_middle_panel: Option<bool>,
+
+    /// Whether to disable search.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    disable_search: Option<bool>,
+
+    /// Whether to hide the loading animation.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    hide_loading: Option<bool>,
+
+    /// Whether to use native scrollbars.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    native_scrollbars: Option<bool>,
+
+    /// Whether to treat the spec as untrusted and sanitize its HTML.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    untrusted_spec: Option<bool>,
+}
+
+impl Default for RedocConfig {
+    fn default() -> Self {
+        Self {
+            spec_url: DEFAULT_SPEC_URL.to_string(),
+            hide_download_button: None,
+            expand_responses: None,
+            json_sample_expand_level: None,
+            theme: None,
+            scroll_y_offset: None,
+            hide_hostname: None,
+            required_props_first: None,
+            sort_props_alphabetically: None,
+            path_in_middle_panel: None,
+            disable_search: None,
+            hide_loading: None,
+            native_scrollbars: None,
+            untrusted_spec: None,
+        }
+    }
+}
+
+const DEFAULT_SPEC_URL: &str = "/openapi.json";
+
+impl RedocConfig {
+    /// Constructs a new [`RedocConfig`] with ReDoc's default settings and the
+    /// spec URL set to `/openapi.json`.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the URL of the OpenAPI spec to be rendered.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use redoc_redist::RedocConfig;
+    /// let mut config = RedocConfig::new();
+    /// config.spec_url("/api-docs/openapi.json");
+    /// ```
+    pub fn spec_url<S: Into<String>>(&mut self, spec_url: S) -> &mut Self {
+        self.spec_url = spec_url.into();
+        self
+    }
+
+    /// Sets whether the spec download button is hidden. Default is `false`.
+    pub fn hide_download_button(&mut self, hide_download_button: bool) -> &mut Self {
+        self.hide_download_button = Some(hide_download_button);
+        self
+    }
+
+    /// Sets the responses expanded by default. By default, all responses are
+    /// collapsed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use redoc_redist::{ExpandResponses, RedocConfig};
+    /// let mut config = RedocConfig::new();
+    /// config.expand_responses(ExpandResponses::StatusCodes(vec![
+    ///     "200".to_string(),
+    ///     "201".to_string(),
+    /// ]));
+    /// ```
+    pub fn expand_responses(&mut self, expand_responses: ExpandResponses) -> &mut Self {
+        self.expand_responses = Some(expand_responses);
+        self
+    }
+
+    /// Sets how deep the JSON samples are expanded by default. Default is 2
+    /// levels.
+    pub fn json_sample_expand_level(&mut self, json_sample_expand_level: ExpandLevel) -> &mut Self {
+        self.json_sample_expand_level = Some(json_sample_expand_level);
+        self
+    }
+
+    /// Sets the ReDoc theme object.
+    ///
+    /// See [the ReDoc documentation](https://redocly.com/docs/redoc/config#theme-settings)
+    /// for the available settings.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use redoc_redist::RedocConfig;
+    /// let mut config = RedocConfig::new();
+    /// config.theme(serde_json::json!({
+    ///     "colors": { "primary": { "main": "#dd5522" } },
+    ///     "typography": { "fontSize": "15px" }
+    /// }));
+    /// ```
+    pub fn theme(&mut self, theme: serde_json::Value) -> &mut Self {
+        self.theme = Some(theme);
+        self
+    }
+
+    /// Sets the offset in pixels from the top of the page to scroll to, e.g.
+    /// to account for a fixed header.
+    pub fn scroll_y_offset(&mut self, scroll_y_offset: u32) -> &mut Self {
+        self.scroll_y_offset = Some(scroll_y_offset);
+        self
+    }
+
+    /// Sets whether the protocol and hostname are hidden in operation paths.
+    /// Default is `false`.
+    pub fn hide_hostname(&mut self, hide_hostname: bool) -> &mut Self {
+        self.hide_hostname = Some(hide_hostname);
+        self
+    }
+
+    /// Sets whether required properties are shown first. Default is `false`.
+    pub fn required_props_first(&mut self, required_props_first: bool) -> &mut Self {
+        self.required_props_first = Some(required_props_first);
+        self
+    }
+
+    /// Sets whether properties are sorted alphabetically. Default is `false`.
+    pub fn sort_props_alphabetically(&mut self, sort_props_alphabetically: bool) -> &mut Self {
+        self.sort_props_alphabetically = Some(sort_props_alphabetically);
+        self
+    }
+
+    /// Sets whether operation paths are shown in the middle panel instead of
+    /// the right one. Default is `false`.
+    pub fn path_in_middle_panel(&mut self, path_in_middle_panel: bool) -> &mut Self {
+        self.path_in_middle_panel = Some(path_in_middle_panel);
+        self
+    }
+
+    /// Sets whether search is disabled. Default is `false`.
+    pub fn disable_search(&mut self, disable_search: bool) -> &mut Self {
+        self.disable_search = Some(disable_search);
+        self
+    }
+
+    /// Sets whether the loading animation is hidden. Default is `false`.
+    pub fn hide_loading(&mut self, hide_loading: bool) -> &mut Self {
+        self.hide_loading = Some(hide_loading);
+        self
+    }
+
+    /// Sets whether native scrollbars are used instead of the custom ones,
+    /// which may improve performance with large specs. Default is `false`.
+    pub fn native_scrollbars(&mut self, native_scrollbars: bool) -> &mut Self {
+        self.native_scrollbars = Some(native_scrollbars);
+        self
+    }
+
+    /// Sets whether the spec is treated as untrusted, which makes ReDoc
+    /// sanitize the HTML in the descriptions. Default is `false`.
+    pub fn untrusted_spec(&mut self, untrusted_spec: bool) -> &mut Self {
+        self.untrusted_spec = Some(untrusted_spec);
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serve_default() {
+        let html = Redoc::new().serve().unwrap();
+
+        assert!(html.contains("<title>ReDoc</title>"));
+        assert!(html.contains(r#"<script src="./redoc.standalone.js" charset="UTF-8"></script>"#));
+        assert!(html.contains(
+            r#"Redoc.init("/openapi.json", {}, document.getElementById("redoc-container"));"#
+        ));
+    }
+
+    #[test]
+    fn serve_escapes_script_end() {
+        let mut redoc = Redoc::new();
+        redoc
+            .config()
+            .spec_url("/openapi.json?</script>")
+            .theme(serde_json::json!({"typography": {"fontFamily": "</script>"}}));
+
+        let html = redoc.serve().unwrap();
+
+        assert_eq!(html.matches("</script>").count(), 2);
+        assert!(html.contains(r#"Redoc.init("/openapi.json?<\/script>""#));
+    }
+
+    #[test]
+    fn config_serialization() {
+        let mut config = RedocConfig::new();
+        config
+            .spec_url("/api.json")
+            .hide_download_button(true)
+            .expand_responses(ExpandResponses::StatusCodes(vec![
+                "200".to_string(),
+                "201".to_string(),
+            ]))
+            .json_sample_expand_level(ExpandLevel::Levels(3))
+            .theme(serde_json::json!({"spacing": {"unit": 4}}))
+            .scroll_y_offset(50)
+            .required_props_first(true);
+
+        assert_eq!(
+            serde_json::to_value(&config).unwrap(),
+            serde_json::json!({
+                "hideDownloadButton": true,
+                "expandResponses": "200,201",
+                "jsonSampleExpandLevel": 3,
+                "theme": {"spacing": {"unit": 4}},
+                "scrollYOffset": 50,
+                "requiredPropsFirst": true,
+            })
+        );
+    }
+
+    #[test]
+    fn expand_all() {
+        let mut config = RedocConfig::new();
+        config
+            .expand_responses(ExpandResponses::All)
+            .json_sample_expand_level(ExpandLevel::All);
+
+        assert_eq!(
+            serde_json::to_string(&config).unwrap(),
+            r#"{"expandResponses":"all","jsonSampleExpandLevel":"all"}"#
+        );
+    }
+
+    #[test]
+    fn file_lookup() {
+        let mut redoc = Redoc::new();
+
+        let js = redoc.file("redoc.standalone.js").unwrap().unwrap();
+        assert_eq!(js.bytes, RedocStaticFile::Js.bytes());
+
+        redoc.override_file_path(RedocStaticFile::Js, "/static/redoc.js".to_string());
+        assert!(redoc.file("redoc.standalone.js").unwrap().is_none());
+        assert!(redoc.serve().unwrap().contains(r#"src="/static/redoc.js""#));
+    }
+}
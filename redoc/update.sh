@@ -0,0 +1,16 @@
+#!/bin/bash
+
+set -e -o pipefail
+
+if [ -z "$1" ]; then
+    echo "USAGE: $0 <version>"
+    echo "Example: $0 v2.0.0"
+    exit 1
+fi
+
+VERSION="$1"
+
+curl -o res/LICENSE https://raw.githubusercontent.com/Redocly/redoc/refs/tags/$VERSION/LICENSE
+curl -o res/redoc.standalone.js https://cdn.jsdelivr.net/npm/redoc@${VERSION#v}/bundles/redoc.standalone.js
+
+sed -i "s/<!-- version -->.*$/<!-- version -->The version of ReDoc included in this crate is $VERSION./" README.md
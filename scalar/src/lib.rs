@@ -0,0 +1,763 @@
+//! This crate implements necessary boilerplate code to serve [Scalar API
+//! Reference] via web server. It provides a simple API to configure Scalar and
+//! serve it via a web server. The crate is deliberately kept simple and does
+//! not implement any web server specific code. It is up to the user to
+//! implement the web server specific code for the web framework of choice.
+//!
+//! It does not download Scalar from the internet, but rather includes the
+//! necessary static files in the crate. This reduces the number of build
+//! dependencies and makes it easy to use the crate offline.
+//!
+//! # Attribution
+//!
+//! [Scalar API Reference] included in this crate is licensed under MIT.
+//!
+//! [Scalar API Reference]: https://github.com/scalar/scalar
+
+#![warn(missing_docs, rustdoc::missing_crate_level_docs)]
+
+use std::borrow::Cow;
+use std::error::Error;
+
+use openapi_gui_core::{FilePaths, GuiFile, OpenApiGui, SpecUrl, StaticFile, escape_html};
+use serde::{Serialize, Serializer};
+
+/// Entry point for serving Scalar API Reference and api docs in application.
+/// It provides builder style chainable configuration methods for configuring
+/// the api doc url and the look of the page.
+///
+/// # Examples
+///
+/// ```
+/// # use scalar_redist::Scalar;
+/// let mut scalar = Scalar::new();
+/// scalar.config().url("/api-docs/openapi.json");
+/// let static_files = Scalar::static_files(); // static files that are needed to be served
+/// let html = scalar.serve()?;
+/// # Ok::<(), Box<dyn std::error::Error + Send + Sync>>(())
+/// ```
+#[non_exhaustive]
+#[derive(Debug, Clone)]
+pub struct Scalar {
+    title: Cow<'static, str>,
+    config: Config,
+    file_paths: FilePaths<ScalarStaticFile>,
+}
+
+impl Default for Scalar {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Scalar {
+    /// Create a new [`Scalar`] with the default configuration.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use scalar_redist::Scalar;
+    /// let scalar = Scalar::new();
+    /// ```
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            title: Cow::Borrowed("Scalar API Reference"),
+            config: Config::new(),
+            file_paths: FilePaths::new(),
+        }
+    }
+
+    /// Return a mutable reference to the config, allowing to modify it.
+    ///
+    /// This is useful for setting up Scalar with custom settings, but also for
+    /// setting up the URL of the OpenAPI spec.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use scalar_redist::{Layout, Scalar, Theme};
+    /// let mut scalar = Scalar::new();
+    /// scalar
+    ///     .config()
+    ///     .url("/api-docs/openapi.json")
+    ///     .theme(Theme::Purple)
+    ///     .layout(Layout::Classic);
+    /// ```
+    pub fn config(&mut self) -> &mut Config {
+        &mut self.config
+    }
+
+    /// Sets the title for the Scalar page.
+    ///
+    /// The title will be displayed in the browser tab.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use scalar_redist::Scalar;
+    /// let mut scalar = Scalar::new();
+    /// scalar.title("My API Documentation");
+    /// ```
+    pub fn title(&mut self, title: impl Into<Cow<'static, str>>) -> &mut Self {
+        self.title = title.into();
+        self
+    }
+
+    /// Returns a reference to all static files required by Scalar.
+    ///
+    /// Each file is paired with its corresponding [`ScalarStaticFile`] enum
+    /// variant for identification.
+    ///
+    /// # Returns
+    ///
+    /// A static slice of tuples containing the file identifier and its raw
+    /// content.
+    #[must_use]
+    pub fn static_files() -> &'static [(ScalarStaticFile, &'static [u8])] {
+        &[(ScalarStaticFile::Js, include_bytes!("../res/standalone.js"))]
+    }
+
+    /// Overrides the path for a specific static file.
+    ///
+    /// This method allows customizing the URL paths where static files are
+    /// served from. This is useful when integrating with web frameworks
+    /// that have specific routing requirements or when serving files from a
+    /// CDN or different location.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use scalar_redist::{Scalar, ScalarStaticFile};
+    /// let mut scalar = Scalar::new();
+    /// scalar.override_file_path(ScalarStaticFile::Js, "/assets/scalar.js".to_string());
+    /// ```
+    pub fn override_file_path(&mut self, static_file: ScalarStaticFile, path: String) {
+        self.file_paths.set(static_file, path);
+    }
+
+    /// Looks up the file that should be served under the given path.
+    ///
+    /// The path is relative to the location Scalar is exposed at. An empty
+    /// path (or `index.html`) resolves to the HTML page generated by
+    /// [`Scalar::serve`], while the remaining paths are matched against the
+    /// (possibly overridden) relative paths of the static files. Leading
+    /// slashes are ignored.
+    ///
+    /// Static files whose path has been overridden with an absolute path or a
+    /// URL are not served, as the generated page does not reference them
+    /// relative to the Scalar location.
+    ///
+    /// # Returns
+    ///
+    /// The file to be served, or `None` if there is no file under given path.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the Scalar config fails to be serialized.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use scalar_redist::Scalar;
+    /// let mut scalar = Scalar::new();
+    /// scalar.config().url("/api-docs/openapi.json");
+    ///
+    /// let index = scalar.file("/")?.expect("index should be present");
+    /// assert_eq!(index.content_type, "text/html; charset=utf-8");
+    ///
+    /// let js = scalar
+    ///     .file("/standalone.js")?
+    ///     .expect("JS should be present");
+    /// assert_eq!(js.content_type, "text/javascript; charset=utf-8");
+    ///
+    /// assert!(scalar.file("/does-not-exist.js")?.is_none());
+    /// # Ok::<(), Box<dyn std::error::Error + Send + Sync>>(())
+    /// ```
+    pub fn file(
+        &self,
+        path: &str,
+    ) -> Result<Option<ScalarFile<'static>>, Box<dyn Error + Send + Sync>> {
+        OpenApiGui::file(self, path)
+    }
+
+    /// Generates the HTML for the Scalar page.
+    ///
+    /// This method creates a complete HTML document that references the
+    /// Scalar JavaScript bundle and passes it the settings specified in the
+    /// [`Config`] object.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the Scalar config fails to be serialized.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use scalar_redist::Scalar;
+    /// let mut scalar = Scalar::new();
+    /// scalar.config().url("/api-docs/openapi.json");
+    /// let html = scalar.serve()?;
+    /// # Ok::<(), Box<dyn std::error::Error + Send + Sync>>(())
+    /// ```
+    pub fn serve(&self) -> Result<String, Box<dyn Error + Send + Sync>> {
+        let title = escape_html(&self.title);
+        let config = serde_json::to_string(&self.config)?;
+        let config = escape_html(&config);
+        let js_path = escape_html(self.file_paths.get(ScalarStaticFile::Js));
+
+        Ok(format!(
+            r#"<!DOCTYPE html>
+<html lang="en">
+<head>
+    <meta charset="UTF-8">
+    <meta name="viewport" content="width=device-width, initial-scale=1">
+    <title>{title}</title>
+    <style>
+        body {{
+            margin: 0;
+        }}
+    </style>
+</head>
+<body>
+<script id="api-reference" data-configuration="{config}"></script>
+<script src="{js_path}" charset="UTF-8"></script>
+</body>
+</html>
+"#
+        ))
+    }
+}
+
+/// Represents the static files required by Scalar.
+#[non_exhaustive]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum ScalarStaticFile {
+    /// The standalone Scalar API Reference JavaScript bundle. It includes the
+    /// stylesheets as well.
+    Js,
+}
+
+impl ScalarStaticFile {
+    /// Returns a slice containing all available Scalar static files.
+    #[must_use]
+    pub fn all() -> &'static [Self] {
+        &[ScalarStaticFile::Js]
+    }
+
+    /// Returns the filename for a specific static file.
+    #[must_use]
+    pub fn file_name(&self) -> &'static str {
+        match self {
+            ScalarStaticFile::Js => "standalone.js",
+        }
+    }
+
+    /// Returns the static file with given filename, if there is one.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use scalar_redist::ScalarStaticFile;
+    /// assert_eq!(
+    ///     ScalarStaticFile::from_file_name("standalone.js"),
+    ///     Some(ScalarStaticFile::Js)
+    /// );
+    /// assert_eq!(ScalarStaticFile::from_file_name("index.html"), None);
+    /// ```
+    #[must_use]
+    pub fn from_file_name(file_name: &str) -> Option<Self> {
+        Self::all()
+            .iter()
+            .copied()
+            .find(|file| file.file_name() == file_name)
+    }
+
+    /// Returns the content type of a specific static file, suitable for use in
+    /// the `Content-Type` header.
+    #[must_use]
+    pub fn content_type(&self) -> &'static str {
+        match self {
+            ScalarStaticFile::Js => "text/javascript; charset=utf-8",
+        }
+    }
+
+    /// Returns the raw content of a specific static file.
+    ///
+    /// This is the same content as returned by [`Scalar::static_files`].
+    #[must_use]
+    #[expect(clippy::missing_panics_doc)]
+    pub fn bytes(&self) -> &'static [u8] {
+        Scalar::static_files()
+            .iter()
+            .find(|(file, _)| file == self)
+            .map(|(_, bytes)| *bytes)
+            .expect("all files should be present")
+    }
+}
+
+impl StaticFile for ScalarStaticFile {
+    fn all() -> &'static [Self] {
+        Self::all()
+    }
+
+    fn file_name(&self) -> &'static str {
+        self.file_name()
+    }
+
+    fn content_type(&self) -> &'static str {
+        self.content_type()
+    }
+
+    fn bytes(&self) -> &'static [u8] {
+        self.bytes()
+    }
+}
+
+impl OpenApiGui for Scalar {
+    type StaticFile = ScalarStaticFile;
+
+    fn set_title(&mut self, title: Cow<'static, str>) {
+        self.title = title;
+    }
+
+    fn set_spec_urls(&mut self, urls: Vec<SpecUrl>) {
+        self.config.url = SpecUrl::primary_of(&urls).map(|url| url.url.clone());
+    }
+
+    fn file_paths(&self) -> &FilePaths<ScalarStaticFile> {
+        &self.file_paths
+    }
+
+    fn file_paths_mut(&mut self) -> &mut FilePaths<ScalarStaticFile> {
+        &mut self.file_paths
+    }
+
+    fn render_index(&self) -> Result<String, Box<dyn Error + Send + Sync>> {
+        self.serve()
+    }
+}
+
+/// Represents servable file of Scalar. This is returned by [`Scalar::file`] to
+/// serve Scalar files via web server.
+pub type ScalarFile<'a> = GuiFile<'a>;
+
+/// Color theme of the Scalar page.
+#[non_exhaustive]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum Theme {
+    /// The default Scalar theme.
+    Default,
+    /// Alternate theme.
+    Alternate,
+    /// Moon theme.
+    Moon,
+    /// Purple theme.
+    Purple,
+    /// Solarized theme.
+    Solarized,
+    /// Blue Planet theme.
+    BluePlanet,
+    /// Deep Space theme.
+    DeepSpace,
+    /// Saturn theme.
+    Saturn,
+    /// Kepler theme.
+    Kepler,
+    /// Mars theme.
+    Mars,
+    /// Laserwave theme.
+    Laserwave,
+    /// No theme at all; useful when styling the page entirely with
+    /// [`Config::custom_css`].
+    None,
+}
+
+/// Page layout of Scalar.
+#[non_exhaustive]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum Layout {
+    /// Sidebar on the left, operations and request examples side by side.
+    Modern,
+    /// Operations stacked in collapsible sections, similar to Swagger UI.
+    Classic,
+}
+
+/// The HTTP clients whose code snippets are hidden in request examples.
+#[non_exhaustive]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum HiddenClients {
+    /// Hide all clients.
+    All,
+    /// Hide the clients with given keys, e.g. `"fetch"`, `"axios"` or
+    /// `"curl"`.
+    Clients(Vec<String>),
+}
+
+impl Serialize for HiddenClients {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            HiddenClients::All => serializer.serialize_bool(true),
+            HiddenClients::Clients(clients) => clients.serialize(serializer),
+        }
+    }
+}
+
+/// A server the requests can be sent to, overriding the servers listed in the
+/// OpenAPI spec.
+#[non_exhaustive]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct Server {
+    url: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    description: Option<String>,
+}
+
+impl Server {
+    /// Creates a new [`Server`] with given URL.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use scalar_redist::Server;
+    /// let server = Server::new("https://api.example.com").description("Production");
+    /// ```
+    #[must_use]
+    pub fn new<S: Into<String>>(url: S) -> Self {
+        Self {
+            url: url.into(),
+            description: None,
+        }
+    }
+
+    /// Sets the description of the server shown in the server selector.
+    #[must_use]
+    pub fn description<S: Into<String>>(mut self, description: S) -> Self {
+        self.description = Some(description.into());
+        self
+    }
+}
+
+/// Object used to alter Scalar settings.
+///
+/// Only the options that were set explicitly are passed to Scalar, so its own
+/// defaults apply to everything else.
+///
+/// # Examples
+///
+/// ```
+/// # use scalar_redist::{Config, Layout, Theme};
+/// let mut config = Config::new();
+/// config
+///     .url("/api-docs/openapi.json")
+///     .theme(Theme::DeepSpace)
+///     .layout(Layout::Modern)
+///     .show_sidebar(true);
+/// ```
+#[non_exhaustive]
+#[derive(Debug, Default, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Config {
+    /// URL of the OpenAPI spec.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    url: Option<String>,
+
+    /// Color theme of the page.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    theme: Option<Theme>,
+
+    /// Page layout.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    layout: Option<Layout>,
+
+    /// URL of the proxy used for sending requests.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    proxy_url: Option<String>,
+
+    /// Servers overriding the ones listed in the OpenAPI spec.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    servers: Vec<Server>,
+
+    /// HTTP clients hidden from request examples.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    hidden_clients: Option<HiddenClients>,
+
+    /// Whether to show the sidebar.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    show_sidebar: Option<bool>,
+
+    /// Whether to hide the models section.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    hide_models: Option<bool>,
+
+    /// Whether to hide the spec download button.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    hide_download_button: Option<bool>,
+
+    /// Whether to hide the "Test Request" button.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    hide_test_request_button: Option<bool>,
+
+    /// Whether to use dark mode by default.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    dark_mode: Option<bool>,
+
+    /// Whether to hide the dark mode toggle.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    hide_dark_mode_toggle: Option<bool>,
+
+    /// Additional CSS applied to the page.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    custom_css: Option<String>,
+}
+
+impl Config {
+    /// Constructs a new [`Config`] with Scalar's default settings.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the URL of the OpenAPI spec to be rendered.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use scalar_redist::Config;
+    /// let mut config = Config::new();
+    /// config.url("/api-docs/openapi.json");
+    /// ```
+    pub fn url<S: Into<String>>(&mut self, url: S) -> &mut Self {
+        self.url = Some(url.into());
+        self
+    }
+
+    /// Sets the color theme of the page.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use scalar_redist::{Config, Theme};
+    /// let mut config = Config::new();
+    /// config.theme(Theme::Moon);
+    /// ```
+    pub fn theme(&mut self, theme: Theme) -> &mut Self {
+        self.theme = Some(theme);
+        self
+    }
+
+    /// Sets the page layout. Default is [`Layout::Modern`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use scalar_redist::{Config, Layout};
+    /// let mut config = Config::new();
+    /// config.layout(Layout::Classic);
+    /// ```
+    pub fn layout(&mut self, layout: Layout) -> &mut Self {
+        self.layout = Some(layout);
+        self
+    }
+
+    /// Sets the URL of a proxy that requests made from the page are sent
+    /// through. This is useful to avoid CORS issues when the API lives on a
+    /// different origin.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use scalar_redist::Config;
+    /// let mut config = Config::new();
+    /// config.proxy_url("https://proxy.scalar.com");
+    /// ```
+    pub fn proxy_url<S: Into<String>>(&mut self, proxy_url: S) -> &mut Self {
+        self.proxy_url = Some(proxy_url.into());
+        self
+    }
+
+    /// Sets the servers that requests can be sent to, overriding the servers
+    /// listed in the OpenAPI spec.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use scalar_redist::{Config, Server};
+    /// let mut config = Config::new();
+    /// config.servers([
+    ///     Server::new("https://api.example.com").description("Production"),
+    ///     Server::new("http://localhost:8000").description("Local"),
+    /// ]);
+    /// ```
+    pub fn servers<I: IntoIterator<Item = Server>>(&mut self, servers: I) -> &mut Self {
+        self.servers = servers.into_iter().collect();
+        self
+    }
+
+    /// Sets the HTTP clients whose code snippets are hidden in request
+    /// examples.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use scalar_redist::{Config, HiddenClients};
+    /// let mut config = Config::new();
+    /// config.hidden_clients(HiddenClients::Clients(vec![
+    ///     "fetch".to_string(),
+    ///     "xhr".to_string(),
+    /// ]));
+    /// ```
+    pub fn hidden_clients(&mut self, hidden_clients: HiddenClients) -> &mut Self {
+        self.hidden_clients = Some(hidden_clients);
+        self
+    }
+
+    /// Sets whether the sidebar is shown. Default is `true`.
+    pub fn show_sidebar(&mut self, show_sidebar: bool) -> &mut Self {
+        self.show_sidebar = Some(show_sidebar);
+        self
+    }
+
+    /// Sets whether the models section is hidden. Default is `false`.
+    pub fn hide_models(&mut self, hide_models: bool) -> &mut Self {
+        self.hide_models = Some(hide_models);
+        self
+    }
+
+    /// Sets whether the spec download button is hidden. Default is `false`.
+    pub fn hide_download_button(&mut self, hide_download_button: bool) -> &mut Self {
+        self.hide_download_button = Some(hide_download_button);
+        self
+    }
+
+    /// Sets whether the "Test Request" button is hidden. Default is `false`.
+    pub fn hide_test_request_button(&mut self, hide_test_request_button: bool) -> &mut Self {
+        self.hide_test_request_button = Some(hide_test_request_button);
+        self
+    }
+
+    /// Sets whether the page uses dark mode by default. If unset, the mode is
+    /// taken from the system preferences.
+    pub fn dark_mode(&mut self, dark_mode: bool) -> &mut Self {
+        self.dark_mode = Some(dark_mode);
+        self
+    }
+
+    /// Sets whether the dark mode toggle is hidden. Default is `false`.
+    pub fn hide_dark_mode_toggle(&mut self, hide_dark_mode_toggle: bool) -> &mut Self {
+        self.hide_dark_mode_toggle = Some(hide_dark_mode_toggle);
+        self
+    }
+
+    /// Sets additional CSS applied to the page.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use scalar_redist::Config;
+    /// let mut config = Config::new();
+    /// config.custom_css(".scalar-app { --scalar-radius: 0; }");
+    /// ```
+    pub fn custom_css<S: Into<String>>(&mut self, custom_css: S) -> &mut Self {
+        self.custom_css = Some(custom_css.into());
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serve_default() {
+        let html = Scalar::new().serve().unwrap();
+
+        assert!(html.contains("<title>Scalar API Reference</title>"));
+        assert!(html.contains(r#"<script id="api-reference" data-configuration="{}"></script>"#));
+        assert!(html.contains(r#"<script src="./standalone.js" charset="UTF-8"></script>"#));
+    }
+
+    #[test]
+    fn serve_escapes_config() {
+        let mut scalar = Scalar::new();
+        scalar.title("<Pets & Co>");
+        scalar
+            .config()
+            .url("/openapi.json?a=1&b=2")
+            .custom_css("body::before { content: '\"</script>'; }");
+
+        let html = scalar.serve().unwrap();
+
+        assert!(html.contains("<title>&lt;Pets &amp; Co&gt;</title>"));
+        assert!(html.contains(
+            "data-configuration=\"{&quot;url&quot;:&quot;/openapi.json?a=1&amp;b=2&quot;,\
+             &quot;customCss&quot;:&quot;body::before { content: &#39;\\&quot;&lt;/script&gt;&#39;; }&quot;}\""
+        ));
+    }
+
+    #[test]
+    fn config_serialization() {
+        let mut config = Config::new();
+        config
+            .url("/openapi.json")
+            .theme(Theme::BluePlanet)
+            .layout(Layout::Classic)
+            .proxy_url("https://proxy.example.com")
+            .servers([Server::new("https://api.example.com").description("Production")])
+            .hidden_clients(HiddenClients::Clients(vec!["fetch".to_string()]))
+            .show_sidebar(false)
+            .dark_mode(true);
+
+        assert_eq!(
+            serde_json::to_value(&config).unwrap(),
+            serde_json::json!({
+                "url": "/openapi.json",
+                "theme": "bluePlanet",
+                "layout": "classic",
+                "proxyUrl": "https://proxy.example.com",
+                "servers": [{"url": "https://api.example.com", "description": "Production"}],
+                "hiddenClients": ["fetch"],
+                "showSidebar": false,
+                "darkMode": true,
+            })
+        );
+    }
+
+    #[test]
+    fn hidden_clients_all() {
+        let mut config = Config::new();
+        config.hidden_clients(HiddenClients::All);
+
+        assert_eq!(
+            serde_json::to_string(&config).unwrap(),
+            r#"{"hiddenClients":true}"#
+        );
+    }
+
+    #[test]
+    fn theme_none_serialization() {
+        assert_eq!(serde_json::to_string(&Theme::None).unwrap(), r#""none""#);
+    }
+
+    #[test]
+    fn file_lookup() {
+        let mut scalar = Scalar::new();
+
+        let js = scalar.file("standalone.js").unwrap().unwrap();
+        assert_eq!(js.bytes, ScalarStaticFile::Js.bytes());
+
+        scalar.override_file_path(
+            ScalarStaticFile::Js,
+            "https://cdn.example.com/scalar.js".to_string(),
+        );
+        assert!(scalar.file("standalone.js").unwrap().is_none());
+        assert!(
+            scalar
+                .serve()
+                .unwrap()
+                .contains(r#"src="https://cdn.example.com/scalar.js""#)
+        );
+    }
+}
@@ -0,0 +1,16 @@
+#!/bin/bash
+
+set -e -o pipefail
+
+if [ -z "$1" ]; then
+    echo "USAGE: $0 <version>"
+    echo "Example: $0 1.31.3"
+    exit 1
+fi
+
+VERSION="$1"
+
+curl -o res/LICENSE https://cdn.jsdelivr.net/npm/@scalar/api-reference@$VERSION/LICENSE
+curl -o res/standalone.js https://cdn.jsdelivr.net/npm/@scalar/api-reference@$VERSION/dist/browser/standalone.min.js
+
+sed -i "s/<!-- version -->.*$/<!-- version -->The version of Scalar API Reference included in this crate is v$VERSION./" README.md
@@ -0,0 +1,7 @@
+/*
+ * Placeholder for the Stoplight Elements web components bundle.
+ *
+ * Run `./update.sh <version>` in the crate directory to download the actual
+ * file from the @stoplight/elements npm package.
+ */
+console.error("Stoplight Elements has not been bundled; run update.sh to download it.");
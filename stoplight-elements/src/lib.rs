@@ -0,0 +1,603 @@
+//! This crate implements necessary boilerplate code to serve [Stoplight
+//! Elements] via web server. It provides a simple API to configure Stoplight
+//! Elements and serve it via a web server. The crate is deliberately kept
+//! simple and does not implement any web server specific code. It is up to the
+//! user to implement the web server specific code for the web framework of
+//! choice.
+//!
+//! It does not download Stoplight Elements from the internet, but rather
+//! includes the necessary static files in the crate. This reduces the number of
+//! build dependencies and makes it easy to use the crate offline.
+//!
+//! Stoplight Elements is configured through the attributes of the
+//! `<elements-api>` element, which are modeled by [`Config`].
+//!
+//! # Attribution
+//!
+//! [Stoplight Elements] included in this crate is licensed under Apache 2.0.
+//!
+//! [Stoplight Elements]: https://github.com/stoplightio/elements
+
+#![warn(missing_docs, rustdoc::missing_crate_level_docs)]
+
+use std::borrow::Cow;
+use std::error::Error;
+
+use openapi_gui_core::{
+    FilePaths, GuiFile, HtmlAttributes, OpenApiGui, SpecUrl, StaticFile, escape_html, is_index_path,
+};
+
+/// Entry point for serving Stoplight Elements and api docs in application. It
+/// provides builder style chainable configuration methods for configuring the
+/// api doc url and the look of the page.
+///
+/// # Examples
+///
+/// ```
+/// # use stoplight_elements_redist::StoplightElements;
+/// let mut elements = StoplightElements::new();
+/// elements.config().api_description_url("/api-docs/openapi.json");
+/// let static_files = StoplightElements::static_files(); // static files that are needed to be served
+/// let html = elements.serve();
+/// ```
+#[non_exhaustive]
+#[derive(Debug, Clone)]
+pub struct StoplightElements {
+    title: Cow<'static, str>,
+    config: Config,
+    file_paths: FilePaths<StoplightElementsStaticFile>,
+}
+
+impl Default for StoplightElements {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl StoplightElements {
+    /// Create a new [`StoplightElements`] with the default configuration.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use stoplight_elements_redist::StoplightElements;
+    /// let elements = StoplightElements::new();
+    /// ```
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            title: Cow::Borrowed("Stoplight Elements"),
+            config: Config::new(),
+            file_paths: FilePaths::new(),
+        }
+    }
+
+    /// Return a mutable reference to the config, allowing to modify it.
+    ///
+    /// This is useful for setting up Stoplight Elements with custom settings,
+    /// but also for setting up the URL of the OpenAPI spec.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use stoplight_elements_redist::{Layout, Router, StoplightElements};
+    /// let mut elements = StoplightElements::new();
+    /// elements
+    ///     .config()
+    ///     .api_description_url("/api-docs/openapi.json")
+    ///     .router(Router::Hash)
+    ///     .layout(Layout::Sidebar);
+    /// ```
+    pub fn config(&mut self) -> &mut Config {
+        &mut self.config
+    }
+
+    /// Sets the title for the Stoplight Elements page.
+    ///
+    /// The title will be displayed in the browser tab.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use stoplight_elements_redist::StoplightElements;
+    /// let mut elements = StoplightElements::new();
+    /// elements.title("My API Documentation");
+    /// ```
+    pub fn title(&mut self, title: impl Into<Cow<'static, str>>) -> &mut Self {
+        self.title = title.into();
+        self
+    }
+
+    /// Returns a reference to all static files required by Stoplight Elements.
+    ///
+    /// Each file is paired with its corresponding
+    /// [`StoplightElementsStaticFile`] enum variant for identification.
+    ///
+    /// # Returns
+    ///
+    /// A static slice of tuples containing the file identifier and its raw
+    /// content.
+    #[must_use]
+    pub fn static_files() -> &'static [(StoplightElementsStaticFile, &'static [u8])] {
+        &[
+            (
+                StoplightElementsStaticFile::Js,
+                include_bytes!("../res/web-components.min.js"),
+            ),
+            (
+                StoplightElementsStaticFile::Css,
+                include_bytes!("../res/styles.min.css"),
+            ),
+        ]
+    }
+
+    /// Overrides the path for a specific static file.
+    ///
+    /// This method allows customizing the URL paths where static files are
+    /// served from. This is useful when integrating with web frameworks
+    /// that have specific routing requirements or when serving files from a
+    /// CDN or different location.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use stoplight_elements_redist::{StoplightElements, StoplightElementsStaticFile};
+    /// let mut elements = StoplightElements::new();
+    /// elements.override_file_path(
+    ///     StoplightElementsStaticFile::Css,
+    ///     "/assets/elements.css".to_string(),
+    /// );
+    /// ```
+    pub fn override_file_path(&mut self, static_file: StoplightElementsStaticFile, path: String) {
+        self.file_paths.set(static_file, path);
+    }
+
+    /// Looks up the file that should be served under the given path.
+    ///
+    /// The path is relative to the location Stoplight Elements is exposed at.
+    /// An empty path (or `index.html`) resolves to the HTML page generated by
+    /// [`StoplightElements::serve`], while the remaining paths are matched
+    /// against the (possibly overridden) relative paths of the static files.
+    /// Leading slashes are ignored.
+    ///
+    /// Static files whose path has been overridden with an absolute path or a
+    /// URL are not served, as the generated page does not reference them
+    /// relative to the Stoplight Elements location.
+    ///
+    /// # Returns
+    ///
+    /// The file to be served, or `None` if there is no file under given path.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use stoplight_elements_redist::StoplightElements;
+    /// let mut elements = StoplightElements::new();
+    /// elements
+    ///     .config()
+    ///     .api_description_url("/api-docs/openapi.json");
+    ///
+    /// let index = elements.file("/").expect("index should be present");
+    /// assert_eq!(index.content_type, "text/html; charset=utf-8");
+    ///
+    /// let css = elements
+    ///     .file("/styles.min.css")
+    ///     .expect("CSS should be present");
+    /// assert_eq!(css.content_type, "text/css; charset=utf-8");
+    ///
+    /// assert!(elements.file("/does-not-exist.js").is_none());
+    /// ```
+    #[must_use]
+    pub fn file(&self, path: &str) -> Option<StoplightElementsFile<'static>> {
+        if is_index_path(path) {
+            return Some(GuiFile::index(self.serve()));
+        }
+
+        self.file_paths.find(path).map(GuiFile::from_static_file)
+    }
+
+    /// Generates the HTML for the Stoplight Elements page.
+    ///
+    /// This method creates a complete HTML document that references the
+    /// Stoplight Elements JavaScript and CSS files and renders the
+    /// `<elements-api>` element with the attributes specified in the
+    /// [`Config`] object. All attribute values are escaped.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use stoplight_elements_redist::StoplightElements;
+    /// let mut elements = StoplightElements::new();
+    /// elements
+    ///     .config()
+    ///     .api_description_url("/api-docs/openapi.json");
+    /// let html = elements.serve();
+    /// assert!(html.contains(r#"apiDescriptionUrl="/api-docs/openapi.json""#));
+    /// ```
+    #[must_use]
+    pub fn serve(&self) -> String {
+        let title = escape_html(&self.title);
+        let js_path = escape_html(self.file_paths.get(StoplightElementsStaticFile::Js));
+        let css_path = escape_html(self.file_paths.get(StoplightElementsStaticFile::Css));
+
+        let attributes = self.config.attributes();
+
+        format!(
+            r#"<!DOCTYPE html>
+<html lang="en">
+<head>
+    <meta charset="UTF-8">
+    <meta name="viewport" content="width=device-width, initial-scale=1, shrink-to-fit=no">
+    <title>{title}</title>
+    <script src="{js_path}"></script>
+    <link rel="stylesheet" href="{css_path}">
+</head>
+<body>
+<elements-api{attributes}
+></elements-api>
+</body>
+</html>
+"#
+        )
+    }
+}
+
+/// Represents the static files required by Stoplight Elements.
+#[non_exhaustive]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum StoplightElementsStaticFile {
+    /// The Stoplight Elements web components JavaScript bundle.
+    Js,
+    /// The Stoplight Elements stylesheet.
+    Css,
+}
+
+impl StoplightElementsStaticFile {
+    /// Returns a slice containing all available Stoplight Elements static
+    /// files.
+    #[must_use]
+    pub fn all() -> &'static [Self] {
+        &[
+            StoplightElementsStaticFile::Js,
+            StoplightElementsStaticFile::Css,
+        ]
+    }
+
+    /// Returns the filename for a specific static file.
+    #[must_use]
+    pub fn file_name(&self) -> &'static str {
+        match self {
+            StoplightElementsStaticFile::Js => "web-components.min.js",
+            StoplightElementsStaticFile::Css => "styles.min.css",
+        }
+    }
+
+    /// Returns the static file with given filename, if there is one.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use stoplight_elements_redist::StoplightElementsStaticFile;
+    /// assert_eq!(
+    ///     StoplightElementsStaticFile::from_file_name("styles.min.css"),
+    ///     Some(StoplightElementsStaticFile::Css)
+    /// );
+    /// assert_eq!(
+    ///     StoplightElementsStaticFile::from_file_name("index.html"),
+    ///     None
+    /// );
+    /// ```
+    #[must_use]
+    pub fn from_file_name(file_name: &str) -> Option<Self> {
+        Self::all()
+            .iter()
+            .copied()
+            .find(|file| file.file_name() == file_name)
+    }
+
+    /// Returns the content type of a specific static file, suitable for use in
+    /// the `Content-Type` header.
+    #[must_use]
+    pub fn content_type(&self) -> &'static str {
+        match self {
+            StoplightElementsStaticFile::Js => "text/javascript; charset=utf-8",
+            StoplightElementsStaticFile::Css => "text/css; charset=utf-8",
+        }
+    }
+
+    /// Returns the raw content of a specific static file.
+    ///
+    /// This is the same content as returned by
+    /// [`StoplightElements::static_files`].
+    #[must_use]
+    #[expect(clippy::missing_panics_doc)]
+    pub fn bytes(&self) -> &'static [u8] {
+        StoplightElements::static_files()
+            .iter()
+            .find(|(file, _)| file == self)
+            .map(|(_, bytes)| *bytes)
+            .expect("all files should be present")
+    }
+}
+
+impl StaticFile for StoplightElementsStaticFile {
+    fn all() -> &'static [Self] {
+        Self::all()
+    }
+
+    fn file_name(&self) -> &'static str {
+        self.file_name()
+    }
+
+    fn content_type(&self) -> &'static str {
+        self.content_type()
+    }
+
+    fn bytes(&self) -> &'static [u8] {
+        self.bytes()
+    }
+}
+
+impl OpenApiGui for StoplightElements {
+    type StaticFile = StoplightElementsStaticFile;
+
+    fn set_title(&mut self, title: Cow<'static, str>) {
+        self.title = title;
+    }
+
+    fn set_spec_urls(&mut self, urls: Vec<SpecUrl>) {
+        self.config.api_description_url = SpecUrl::primary_of(&urls).map(|url| url.url.clone());
+    }
+
+    fn file_paths(&self) -> &FilePaths<StoplightElementsStaticFile> {
+        &self.file_paths
+    }
+
+    fn file_paths_mut(&mut self) -> &mut FilePaths<StoplightElementsStaticFile> {
+        &mut self.file_paths
+    }
+
+    fn render_index(&self) -> Result<String, Box<dyn Error + Send + Sync>> {
+        Ok(self.serve())
+    }
+}
+
+/// Represents servable file of Stoplight Elements. This is returned by
+/// [`StoplightElements::file`] to serve Stoplight Elements files via web
+/// server.
+pub type StoplightElementsFile<'a> = GuiFile<'a>;
+
+/// How Stoplight Elements keeps track of the current page.
+#[non_exhaustive]
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Router {
+    /// In the URL path, using the History API. The server has to serve the
+    /// page under every sub-path of the base path.
+    History,
+    /// In the URL fragment. Works without any server support.
+    Hash,
+    /// In memory only; the URL does not change.
+    Memory,
+    /// Renders a single page for static rendering.
+    Static,
+}
+
+impl Router {
+    fn as_str(self) -> &'static str {
+        match self {
+            Router::History => "history",
+            Router::Hash => "hash",
+            Router::Memory => "memory",
+            Router::Static => "static",
+        }
+    }
+}
+
+/// Page layout of Stoplight Elements.
+#[non_exhaustive]
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Layout {
+    /// Three-column layout with a sidebar on the left.
+    Sidebar,
+    /// Single-column layout, suitable for embedding in other pages.
+    Stacked,
+    /// The sidebar layout on wide screens, collapsing the sidebar on narrow
+    /// ones.
+    Responsive,
+}
+
+impl Layout {
+    fn as_str(self) -> &'static str {
+        match self {
+            Layout::Sidebar => "sidebar",
+            Layout::Stacked => "stacked",
+            Layout::Responsive => "responsive",
+        }
+    }
+}
+
+/// Object used to alter Stoplight Elements settings. Every setting maps to an
+/// attribute of the `<elements-api>` element.
+///
+/// Only the attributes that were set explicitly are rendered, so the defaults
+/// of Stoplight Elements apply to everything else.
+///
+/// # Examples
+///
+/// ```
+/// # use stoplight_elements_redist::{Config, Layout, Router};
+/// let mut config = Config::new();
+/// config
+///     .api_description_url("/api-docs/openapi.json")
+///     .router(Router::Hash)
+///     .layout(Layout::Stacked)
+///     .hide_try_it(true)
+///     .logo("/static/logo.svg");
+/// ```
+#[non_exhaustive]
+#[derive(Debug, Default, Clone)]
+pub struct Config {
+    api_description_url: Option<String>,
+    router: Option<Router>,
+    base_path: Option<String>,
+    layout: Option<Layout>,
+    hide_try_it: Option<bool>,
+    hide_schemas: Option<bool>,
+    hide_internal: Option<bool>,
+    hide_export: Option<bool>,
+    logo: Option<String>,
+}
+
+impl Config {
+    /// Constructs a new [`Config`] with the default Stoplight Elements
+    /// settings.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the URL of the OpenAPI spec to be rendered (`apiDescriptionUrl`).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use stoplight_elements_redist::Config;
+    /// let mut config = Config::new();
+    /// config.api_description_url("/api-docs/openapi.json");
+    /// ```
+    pub fn api_description_url<S: Into<String>>(&mut self, api_description_url: S) -> &mut Self {
+        self.api_description_url = Some(api_description_url.into());
+        self
+    }
+
+    /// Sets how the current page is tracked (`router`). Default is
+    /// [`Router::History`], which requires the server to serve the page under
+    /// every sub-path; [`Router::Hash`] works without any server support.
+    pub fn router(&mut self, router: Router) -> &mut Self {
+        self.router = Some(router);
+        self
+    }
+
+    /// Sets the path the page is served under when using [`Router::History`]
+    /// (`basePath`).
+    pub fn base_path<S: Into<String>>(&mut self, base_path: S) -> &mut Self {
+        self.base_path = Some(base_path.into());
+        self
+    }
+
+    /// Sets the page layout (`layout`). Default is [`Layout::Sidebar`].
+    pub fn layout(&mut self, layout: Layout) -> &mut Self {
+        self.layout = Some(layout);
+        self
+    }
+
+    /// Sets whether the "Try It" panel is hidden (`hideTryIt`). Default is
+    /// `false`.
+    pub fn hide_try_it(&mut self, hide_try_it: bool) -> &mut Self {
+        self.hide_try_it = Some(hide_try_it);
+        self
+    }
+
+    /// Sets whether the schemas are hidden from the table of contents
+    /// (`hideSchemas`). Default is `false`.
+    pub fn hide_schemas(&mut self, hide_schemas: bool) -> &mut Self {
+        self.hide_schemas = Some(hide_schemas);
+        self
+    }
+
+    /// Sets whether the operations and models marked as internal
+    /// (`x-internal`) are hidden (`hideInternal`). Default is `false`.
+    pub fn hide_internal(&mut self, hide_internal: bool) -> &mut Self {
+        self.hide_internal = Some(hide_internal);
+        self
+    }
+
+    /// Sets whether the spec export button is hidden (`hideExport`). Default
+    /// is `false`.
+    pub fn hide_export(&mut self, hide_export: bool) -> &mut Self {
+        self.hide_export = Some(hide_export);
+        self
+    }
+
+    /// Sets the URL of the logo shown in the top left corner (`logo`).
+    pub fn logo<S: Into<String>>(&mut self, logo: S) -> &mut Self {
+        self.logo = Some(logo.into());
+        self
+    }
+
+    /// Returns the `<elements-api>` attributes for the settings that were set.
+    fn attributes(&self) -> HtmlAttributes<'_> {
+        let mut attributes = HtmlAttributes::new();
+        attributes
+            .text("apiDescriptionUrl", self.api_description_url.as_deref())
+            .text("router", self.router.map(Router::as_str))
+            .text("basePath", self.base_path.as_deref())
+            .text("layout", self.layout.map(Layout::as_str))
+            .flag("hideTryIt", self.hide_try_it)
+            .flag("hideSchemas", self.hide_schemas)
+            .flag("hideInternal", self.hide_internal)
+            .flag("hideExport", self.hide_export)
+            .text("logo", self.logo.as_deref());
+        attributes
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serve_default() {
+        let html = StoplightElements::new().serve();
+
+        assert!(html.contains("<title>Stoplight Elements</title>"));
+        assert!(html.contains(r#"<script src="./web-components.min.js"></script>"#));
+        assert!(html.contains(r#"<link rel="stylesheet" href="./styles.min.css">"#));
+        assert!(html.contains("<elements-api\n></elements-api>"));
+    }
+
+    #[test]
+    fn serve_attributes() {
+        let mut elements = StoplightElements::new();
+        elements
+            .config()
+            .api_description_url("/openapi.json")
+            .router(Router::Hash)
+            .layout(Layout::Sidebar)
+            .hide_try_it(true)
+            .hide_schemas(false)
+            .logo("/logo.svg?a=1&b=2");
+
+        let html = elements.serve();
+
+        assert!(html.contains(
+            r#"<elements-api
+    apiDescriptionUrl="/openapi.json"
+    router="hash"
+    layout="sidebar"
+    hideTryIt="true"
+    hideSchemas="false"
+    logo="/logo.svg?a=1&amp;b=2"
+></elements-api>"#
+        ));
+    }
+
+    #[test]
+    fn file_lookup() {
+        let mut elements = StoplightElements::new();
+
+        let js = elements.file("web-components.min.js").unwrap();
+        assert_eq!(js.bytes, StoplightElementsStaticFile::Js.bytes());
+
+        elements.override_file_path(
+            StoplightElementsStaticFile::Js,
+            "https://cdn.example.com/elements.js".to_string(),
+        );
+        assert!(elements.file("web-components.min.js").is_none());
+        assert!(
+            elements
+                .serve()
+                .contains(r#"<script src="https://cdn.example.com/elements.js"></script>"#)
+        );
+    }
+}
@@ -0,0 +1,17 @@
+#!/bin/bash
+
+set -e -o pipefail
+
+if [ -z "$1" ]; then
+    echo "USAGE: $0 <version>"
+    echo "Example: $0 v8.5.2"
+    exit 1
+fi
+
+VERSION="$1"
+
+curl -o res/LICENSE https://raw.githubusercontent.com/stoplightio/elements/refs/tags/$VERSION/LICENSE
+curl -o res/web-components.min.js https://cdn.jsdelivr.net/npm/@stoplight/elements@${VERSION#v}/web-components.min.js
+curl -o res/styles.min.css https://cdn.jsdelivr.net/npm/@stoplight/elements@${VERSION#v}/styles.min.css
+
+sed -i "s/<!-- version -->.*$/<!-- version -->The version of Stoplight Elements included in this crate is $VERSION./" README.md
@@ -0,0 +1,171 @@
+//! A command line tool for previewing OpenAPI specs locally with the Swagger UI
+//! bundled in [`swagger_ui_redist`].
+//!
+//! The tool starts a minimal HTTP server on localhost that serves Swagger UI
+//! along with given OpenAPI spec file (JSON or YAML). The page is reloaded
+//! automatically whenever the spec file changes.
+//!
+//! ```text
+//! swagger-ui-preview openapi.yaml --port 8080
+//! ```
+
+mod server;
+
+use std::error::Error;
+use std::net::{IpAddr, Ipv4Addr, SocketAddr, TcpListener};
+use std::path::PathBuf;
+use std::process::ExitCode;
+
+use crate::server::Server;
+
+const DEFAULT_PORT: u16 = 8080;
+
+const USAGE: &str = "\
+Preview OpenAPI specs locally with the bundled Swagger UI.
+
+Usage: swagger-ui-preview [OPTIONS] <SPEC_FILE>
+
+Arguments:
+  <SPEC_FILE>  Path to the OpenAPI spec file (JSON or YAML)
+
+Options:
+  -p, --port <PORT>  Port to listen on [default: 8080]
+      --host <HOST>  Address to listen on [default: 127.0.0.1]
+  -h, --help         Print help
+  -V, --version      Print version
+";
+
+#[derive(Debug, PartialEq, Eq)]
+enum Command {
+    Serve(Args),
+    Help,
+    Version,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+struct Args {
+    spec_file: PathBuf,
+    address: SocketAddr,
+}
+
+fn main() -> ExitCode {
+    match parse_args(std::env::args().skip(1)) {
+        Ok(Command::Serve(args)) => match serve(&args) {
+            Ok(()) => ExitCode::SUCCESS,
+            Err(error) => {
+                eprintln!("error: {error}");
+                ExitCode::FAILURE
+            }
+        },
+        Ok(Command::Help) => {
+            print!("{USAGE}");
+            ExitCode::SUCCESS
+        }
+        Ok(Command::Version) => {
+            println!("swagger-ui-preview {}", env!("CARGO_PKG_VERSION"));
+            ExitCode::SUCCESS
+        }
+        Err(error) => {
+            eprintln!("error: {error}\n\n{USAGE}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn serve(args: &Args) -> Result<(), Box<dyn Error + Send + Sync>> {
+    if !args.spec_file.is_file() {
+        return Err(format!("spec file not found: {}", args.spec_file.display()).into());
+    }
+
+    let server = Server::new(args.spec_file.clone())?;
+    let listener = TcpListener::bind(args.address)?;
+    println!(
+        "Serving {} at http://{}/",
+        args.spec_file.display(),
+        listener.local_addr()?
+    );
+
+    server.run(&listener);
+    Ok(())
+}
+
+fn parse_args<I: IntoIterator<Item = String>>(args: I) -> Result<Command, String> {
+    let mut args = args.into_iter();
+    let mut spec_file = None;
+    let mut host = IpAddr::V4(Ipv4Addr::LOCALHOST);
+    let mut port = DEFAULT_PORT;
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "-h" | "--help" => return Ok(Command::Help),
+            "-V" | "--version" => return Ok(Command::Version),
+            "-p" | "--port" => {
+                let value = args.next().ok_or("missing value for --port")?;
+                port = value
+                    .parse()
+                    .map_err(|_| format!("invalid port: {value}"))?;
+            }
+            "--host" => {
+                let value = args.next().ok_or("missing value for --host")?;
+                host = value
+                    .parse()
+                    .map_err(|_| format!("invalid host: {value}"))?;
+            }
+            option if option.starts_with('-') => {
+                return Err(format!("unknown option: {option}"));
+            }
+            _ if spec_file.is_some() => {
+                return Err(format!("unexpected argument: {arg}"));
+            }
+            _ => spec_file = Some(PathBuf::from(arg)),
+        }
+    }
+
+    let spec_file = spec_file.ok_or("missing spec file")?;
+    Ok(Command::Serve(Args {
+        spec_file,
+        address: SocketAddr::new(host, port),
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(args: &[&str]) -> Result<Command, String> {
+        parse_args(args.iter().map(ToString::to_string))
+    }
+
+    #[test]
+    fn parse_spec_file() {
+        assert_eq!(
+            parse(&["openapi.yaml"]),
+            Ok(Command::Serve(Args {
+                spec_file: PathBuf::from("openapi.yaml"),
+                address: SocketAddr::from(([127, 0, 0, 1], DEFAULT_PORT)),
+            }))
+        );
+    }
+
+    #[test]
+    fn parse_options() {
+        assert_eq!(
+            parse(&["--port", "3000", "openapi.json", "--host", "0.0.0.0"]),
+            Ok(Command::Serve(Args {
+                spec_file: PathBuf::from("openapi.json"),
+                address: SocketAddr::from(([0, 0, 0, 0], 3000)),
+            }))
+        );
+        assert_eq!(parse(&["-h"]), Ok(Command::Help));
+        assert_eq!(parse(&["openapi.json", "--version"]), Ok(Command::Version));
+    }
+
+    #[test]
+    fn parse_errors() {
+        assert!(parse(&[]).is_err());
+        assert!(parse(&["a.json", "b.json"]).is_err());
+        assert!(parse(&["--port", "abc", "a.json"]).is_err());
+        assert!(parse(&["--port"]).is_err());
+        assert!(parse(&["--unknown", "a.json"]).is_err());
+    }
+}
@@ -0,0 +1,489 @@
+//! A minimal, std-only HTTP/1.1 server serving Swagger UI and the spec file.
+
+use std::borrow::Cow;
+use std::error::Error;
+use std::io::{self, BufRead, BufReader, Write};
+use std::net::{TcpListener, TcpStream};
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Condvar, Mutex, PoisonError};
+use std::time::{Duration, UNIX_EPOCH};
+use std::{fs, thread};
+
+use swagger_ui_redist::SwaggerUi;
+
+/// Path polled by the page to find out whether the spec file has changed.
+const RELOAD_PATH: &str = "/__reload";
+
+/// Maximum size of the request line and headers.
+const MAX_HEADER_SIZE: u64 = 16 * 1024;
+
+/// Maximum number of connections handled at the same time. Further
+/// connections wait in the listen backlog until one of the handlers finishes.
+const MAX_CONNECTIONS: usize = 32;
+
+/// Timeout for reading the request from and writing the response to a client,
+/// so that stalled clients don't hold a connection slot forever.
+const IO_TIMEOUT: Duration = Duration::from_secs(10);
+
+const RELOAD_SCRIPT: &str = r#"<script>
+    (() => {
+        let version = null;
+        setInterval(async () => {
+            try {
+                const response = await fetch("./__reload", { cache: "no-store" });
+                const currentVersion = await response.text();
+                if (version !== null && currentVersion !== version) {
+                    window.ui.specActions.download();
+                }
+                version = currentVersion;
+            } catch (error) {
+                // the server is probably restarting; try again later
+            }
+        }, 1000);
+    })();
+</script>
+"#;
+
+#[derive(Debug)]
+pub(crate) struct Server {
+    spec_file: PathBuf,
+    spec_path: &'static str,
+    index: String,
+    swagger_ui: SwaggerUi,
+}
+
+impl Server {
+    pub(crate) fn new(spec_file: PathBuf) -> Result<Self, Box<dyn Error + Send + Sync>> {
+        let file_name = spec_file
+            .file_name()
+            .and_then(|file_name| file_name.to_str())
+            .ok_or("spec file name should be valid UTF-8")?;
+        // the spec is served under a fixed name, so that file names which are not
+        // valid in a URL path (e.g. containing spaces or `#`) don't need encoding
+        let spec_path = match spec_content_type(&spec_file) {
+            YAML_CONTENT_TYPE => "/spec/openapi.yaml",
+            _ => "/spec/openapi.json",
+        };
+
+        let mut swagger_ui = SwaggerUi::new();
+        swagger_ui.title(format!("{file_name} - Swagger UI"));
+        swagger_ui.config().urls([format!(".{spec_path}")]);
+        swagger_ui.template().body_end(RELOAD_SCRIPT);
+        let index = swagger_ui.serve()?;
+
+        Ok(Self {
+            spec_file,
+            spec_path,
+            index,
+            swagger_ui,
+        })
+    }
+
+    pub(crate) fn run(self, listener: &TcpListener) {
+        let server = Arc::new(self);
+        let connections = Arc::new(ConnectionLimit::new(MAX_CONNECTIONS));
+
+        for stream in listener.incoming() {
+            match stream {
+                Ok(stream) => {
+                    if let Err(error) = set_timeouts(&stream) {
+                        eprintln!("error setting up connection: {error}");
+                        continue;
+                    }
+
+                    let slot = ConnectionLimit::acquire(&connections);
+                    let server = Arc::clone(&server);
+                    thread::spawn(move || {
+                        let _slot = slot;
+                        if let Err(error) = server.handle_connection(stream) {
+                            eprintln!("error handling connection: {error}");
+                        }
+                    });
+                }
+                Err(error) => eprintln!("error accepting connection: {error}"),
+            }
+        }
+    }
+
+    fn handle_connection(&self, mut stream: TcpStream) -> io::Result<()> {
+        let response = match read_request(BufReader::new(&stream))? {
+            Some(request) => self.respond(&request),
+            None => Response::empty(400, "Bad Request"),
+        };
+
+        response.write_to(&mut stream)
+    }
+
+    fn respond(&self, request: &Request) -> Response {
+        if request.method != "GET" && request.method != "HEAD" {
+            let mut response = Response::empty(405, "Method Not Allowed");
+            response.headers.push(("Allow", "GET, HEAD".to_string()));
+            return response;
+        }
+
+        let mut response = self.route(request);
+        if request.method == "HEAD" {
+            response.body = Cow::Borrowed(&[]);
+        }
+        response
+    }
+
+    fn route(&self, request: &Request) -> Response {
+        let path = request.path.as_str();
+
+        if path == RELOAD_PATH {
+            return Response::ok("text/plain; charset=utf-8", spec_version(&self.spec_file))
+                .no_cache();
+        }
+        if path == self.spec_path {
+            return match fs::read(&self.spec_file) {
+                Ok(content) => Response::ok(spec_content_type(&self.spec_file), content).no_cache(),
+                Err(error) => {
+                    eprintln!("error reading {}: {error}", self.spec_file.display());
+                    Response::empty(404, "Not Found")
+                }
+            };
+        }
+        if path == "/" || path == "/index.html" {
+            return Response::ok("text/html; charset=utf-8", self.index.clone());
+        }
+
+        match self.swagger_ui.file(path) {
+            Ok(Some(file)) => {
+                let range_response =
+                    file.range_response(request.range.as_deref(), request.if_range.as_deref());
+                let (status, reason) = match range_response.status {
+                    206 => (206, "Partial Content"),
+                    416 => (416, "Range Not Satisfiable"),
+                    _ => (200, "OK"),
+                };
+
+                let mut headers = vec![
+                    ("Content-Type", range_response.content_type.into_owned()),
+                    ("Accept-Ranges", "bytes".to_string()),
+                    ("ETag", file.etag.clone()),
+                ];
+                if let Some(content_range) = range_response.content_range {
+                    headers.push(("Content-Range", content_range));
+                }
+
+                Response {
+                    status,
+                    reason,
+                    headers,
+                    body: Cow::Owned(range_response.body.into_owned()),
+                }
+            }
+            Ok(None) => Response::empty(404, "Not Found"),
+            Err(error) => {
+                eprintln!("error serving Swagger UI: {error}");
+                Response::empty(500, "Internal Server Error")
+            }
+        }
+    }
+}
+
+fn set_timeouts(stream: &TcpStream) -> io::Result<()> {
+    stream.set_read_timeout(Some(IO_TIMEOUT))?;
+    stream.set_write_timeout(Some(IO_TIMEOUT))
+}
+
+/// Limits the number of connections handled at the same time.
+#[derive(Debug)]
+struct ConnectionLimit {
+    max: usize,
+    active: Mutex<usize>,
+    released: Condvar,
+}
+
+impl ConnectionLimit {
+    fn new(max: usize) -> Self {
+        Self {
+            max,
+            active: Mutex::new(0),
+            released: Condvar::new(),
+        }
+    }
+
+    /// Blocks until a connection slot is available and takes it. The slot is
+    /// released when the returned guard is dropped.
+    fn acquire(limit: &Arc<Self>) -> ConnectionSlot {
+        let mut active = limit.active.lock().unwrap_or_else(PoisonError::into_inner);
+        while *active >= limit.max {
+            active = limit
+                .released
+                .wait(active)
+                .unwrap_or_else(PoisonError::into_inner);
+        }
+        *active += 1;
+
+        ConnectionSlot {
+            limit: Arc::clone(limit),
+        }
+    }
+}
+
+#[derive(Debug)]
+struct ConnectionSlot {
+    limit: Arc<ConnectionLimit>,
+}
+
+impl Drop for ConnectionSlot {
+    fn drop(&mut self) {
+        let mut active = self
+            .limit
+            .active
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner);
+        *active -= 1;
+        self.limit.released.notify_one();
+    }
+}
+
+/// Returns a string that changes whenever the spec file is modified.
+fn spec_version(spec_file: &Path) -> String {
+    match fs::metadata(spec_file) {
+        Ok(metadata) => {
+            let modified = metadata
+                .modified()
+                .ok()
+                .and_then(|modified| modified.duration_since(UNIX_EPOCH).ok())
+                .unwrap_or_default();
+
+            format!("{}-{}", modified.as_nanos(), metadata.len())
+        }
+        Err(_) => "missing".to_string(),
+    }
+}
+
+const YAML_CONTENT_TYPE: &str = "application/yaml; charset=utf-8";
+const JSON_CONTENT_TYPE: &str = "application/json; charset=utf-8";
+
+fn spec_content_type(spec_file: &Path) -> &'static str {
+    match spec_file
+        .extension()
+        .and_then(|extension| extension.to_str())
+    {
+        Some("yaml" | "yml") => YAML_CONTENT_TYPE,
+        _ => JSON_CONTENT_TYPE,
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+struct Request {
+    method: String,
+    path: String,
+    range: Option<String>,
+    if_range: Option<String>,
+}
+
+/// Reads the request line and headers of an HTTP request.
+///
+/// Returns `None` if the request is malformed. The request body, if any, is
+/// ignored, as only `GET` and `HEAD` requests are supported.
+fn read_request<R: BufRead>(reader: R) -> io::Result<Option<Request>> {
+    let mut reader = reader.take(MAX_HEADER_SIZE);
+
+    let mut request_line = String::new();
+    reader.read_line(&mut request_line)?;
+    let mut parts = request_line.split_whitespace();
+    let (Some(method), Some(target), Some(_version)) = (parts.next(), parts.next(), parts.next())
+    else {
+        return Ok(None);
+    };
+    let path = target.split(['?', '#']).next().unwrap_or_default();
+
+    let mut request = Request {
+        method: method.to_string(),
+        path: path.to_string(),
+        range: None,
+        if_range: None,
+    };
+
+    loop {
+        let mut line = String::new();
+        if reader.read_line(&mut line)? == 0 {
+            // headers too long or connection closed before they ended
+            return Ok(None);
+        }
+        let line = line.trim_end_matches(['\r', '\n']);
+        if line.is_empty() {
+            break;
+        }
+
+        let Some((name, value)) = line.split_once(':') else {
+            return Ok(None);
+        };
+        let value = value.trim().to_string();
+        if name.eq_ignore_ascii_case("range") {
+            request.range = Some(value);
+        } else if name.eq_ignore_ascii_case("if-range") {
+            request.if_range = Some(value);
+        }
+    }
+
+    Ok(Some(request))
+}
+
+#[derive(Debug)]
+struct Response {
+    status: u16,
+    reason: &'static str,
+    headers: Vec<(&'static str, String)>,
+    body: Cow<'static, [u8]>,
+}
+
+impl Response {
+    fn ok<B: Into<Vec<u8>>>(content_type: &str, body: B) -> Self {
+        Self {
+            status: 200,
+            reason: "OK",
+            headers: vec![("Content-Type", content_type.to_string())],
+            body: Cow::Owned(body.into()),
+        }
+    }
+
+    fn empty(status: u16, reason: &'static str) -> Self {
+        Self {
+            status,
+            reason,
+            headers: Vec::new(),
+            body: Cow::Borrowed(&[]),
+        }
+    }
+
+    fn no_cache(mut self) -> Self {
+        self.headers.push(("Cache-Control", "no-cache".to_string()));
+        self
+    }
+
+    fn write_to<W: Write>(&self, writer: &mut W) -> io::Result<()> {
+        let mut head = Vec::new();
+        write!(head, "HTTP/1.1 {} {}\r\n", self.status, self.reason)?;
+        for (name, value) in &self.headers {
+            write!(head, "{name}: {value}\r\n")?;
+        }
+        write!(
+            head,
+            "Content-Length: {}\r\nConnection: close\r\n\r\n",
+            self.body.len()
+        )?;
+
+        writer.write_all(&head)?;
+        writer.write_all(&self.body)?;
+        writer.flush()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn read_request_parses_headers() {
+        let input: &[u8] =
+            b"GET /swagger-ui.css?v=1 HTTP/1.1\r\nHost: localhost\r\nRange: bytes=0-9\r\nIf-Range: \"abc\"\r\n\r\n";
+
+        assert_eq!(
+            read_request(input).unwrap(),
+            Some(Request {
+                method: "GET".to_string(),
+                path: "/swagger-ui.css".to_string(),
+                range: Some("bytes=0-9".to_string()),
+                if_range: Some("\"abc\"".to_string()),
+            })
+        );
+    }
+
+    #[test]
+    fn read_request_malformed() {
+        let no_version: &[u8] = b"GET /\r\n\r\n";
+        let unterminated: &[u8] = b"GET / HTTP/1.1\r\nHost: localhost\r\n";
+        let invalid_header: &[u8] = b"GET / HTTP/1.1\r\nHost\r\n\r\n";
+
+        assert_eq!(read_request(no_version).unwrap(), None);
+        assert_eq!(read_request(unterminated).unwrap(), None);
+        assert_eq!(read_request(invalid_header).unwrap(), None);
+    }
+
+    #[test]
+    fn response_write_to() {
+        let mut output = Vec::new();
+
+        Response::ok("text/plain", "hello")
+            .write_to(&mut output)
+            .unwrap();
+
+        assert_eq!(
+            String::from_utf8(output).unwrap(),
+            "HTTP/1.1 200 OK\r\nContent-Type: text/plain\r\nContent-Length: 5\r\nConnection: close\r\n\r\nhello"
+        );
+    }
+
+    #[test]
+    fn connection_limit_blocks_until_released() {
+        let limit = Arc::new(ConnectionLimit::new(1));
+        let slot = ConnectionLimit::acquire(&limit);
+
+        let waiting = {
+            let limit = Arc::clone(&limit);
+            thread::spawn(move || drop(ConnectionLimit::acquire(&limit)))
+        };
+        thread::sleep(Duration::from_millis(50));
+        assert!(!waiting.is_finished());
+
+        drop(slot);
+        waiting.join().unwrap();
+        assert_eq!(*limit.active.lock().unwrap(), 0);
+    }
+
+    #[test]
+    fn spec_content_types() {
+        assert_eq!(
+            spec_content_type(Path::new("openapi.yaml")),
+            "application/yaml; charset=utf-8"
+        );
+        assert_eq!(
+            spec_content_type(Path::new("openapi.yml")),
+            "application/yaml; charset=utf-8"
+        );
+        assert_eq!(
+            spec_content_type(Path::new("openapi.json")),
+            "application/json; charset=utf-8"
+        );
+    }
+
+    #[test]
+    fn index_contains_spec_url_and_reload_script() {
+        let server = Server::new(PathBuf::from("specs/openapi.yaml")).unwrap();
+
+        assert!(server.index.contains(r#""url": "./spec/openapi.yaml""#));
+        assert!(server.index.contains(&format!("{RELOAD_SCRIPT}</body>")));
+    }
+
+    #[test]
+    fn spec_served_under_fixed_path() {
+        let dir = std::env::temp_dir().join(format!("swagger-ui-cli-test-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        let spec_file = dir.join("my api #1.yml");
+        fs::write(&spec_file, "openapi: 3.0.3").unwrap();
+
+        let server = Server::new(spec_file).unwrap();
+        let response = server.route(&Request {
+            method: "GET".to_string(),
+            path: "/spec/openapi.yaml".to_string(),
+            range: None,
+            if_range: None,
+        });
+        fs::remove_dir_all(&dir).unwrap();
+
+        assert!(server.index.contains(r#""url": "./spec/openapi.yaml""#));
+        assert!(
+            server
+                .index
+                .contains("<title>my api #1.yml - Swagger UI</title>")
+        );
+        assert_eq!(response.status, 200);
+        assert_eq!(&*response.body, b"openapi: 3.0.3");
+    }
+}
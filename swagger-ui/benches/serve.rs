@@ -0,0 +1,56 @@
+//! Compares rendering the Swagger UI page on every request with serving the
+//! page rendered once by `SwaggerUi::freeze`.
+//!
+//! Run with `cargo bench -p swagger-ui-redist`.
+
+use divan::Bencher;
+use swagger_ui_redist::{SwaggerUi, Url};
+
+fn main() {
+    divan::main();
+}
+
+/// A Swagger UI with a typical config: a few spec URLs and some options.
+fn swagger_ui() -> SwaggerUi {
+    let mut swagger = SwaggerUi::new();
+    swagger
+        .title("Benchmark API")
+        .config()
+        .urls([
+            Url::new("v1", "/api-docs/openapi-v1.json"),
+            Url::new("v2", "/api-docs/openapi-v2.json").primary(true),
+            Url::new("internal", "/api-docs/internal.json"),
+        ])
+        .display_request_duration(true)
+        .filter(true)
+        .persist_authorization(true);
+    swagger
+}
+
+#[divan::bench]
+fn serve(bencher: Bencher<'_, '_>) {
+    let swagger = swagger_ui();
+
+    bencher.bench(|| swagger.serve().expect("page should be rendered"));
+}
+
+#[divan::bench]
+fn serve_index_file(bencher: Bencher<'_, '_>) {
+    let swagger = swagger_ui();
+
+    bencher.bench(|| swagger.file("/").expect("page should be rendered"));
+}
+
+#[divan::bench]
+fn frozen_index(bencher: Bencher<'_, '_>) {
+    let frozen = swagger_ui().freeze().expect("page should be rendered");
+
+    bencher.bench(|| frozen.index());
+}
+
+#[divan::bench]
+fn frozen_index_file(bencher: Bencher<'_, '_>) {
+    let frozen = swagger_ui().freeze().expect("page should be rendered");
+
+    bencher.bench(|| frozen.file("/"));
+}
@@ -0,0 +1,97 @@
+/*
+ * Dark theme switcher for Swagger UI, maintained as part of swagger-ui-redist.
+ *
+ * Sets the `swagger-ui-dark` class on the root element when the dark theme is
+ * active, and adds a button to the top bar that toggles it. The user's choice
+ * is remembered in the local storage; until one is made, the theme given in
+ * the `data-default-theme` attribute of the script tag is used, where `system`
+ * follows the `prefers-color-scheme` media query.
+ */
+(function () {
+    'use strict';
+
+    var STORAGE_KEY = 'swagger-ui-theme';
+    var DARK_CLASS = 'swagger-ui-dark';
+    var TOGGLE_CLASS = 'swagger-ui-theme-toggle';
+
+    var script = document.currentScript;
+    var defaultTheme = (script && script.getAttribute('data-default-theme')) || 'system';
+    var media = window.matchMedia ? window.matchMedia('(prefers-color-scheme: dark)') : null;
+    var chosenTheme = storedTheme();
+
+    function storedTheme() {
+        try {
+            return window.localStorage.getItem(STORAGE_KEY);
+        } catch (e) {
+            return null;
+        }
+    }
+
+    function isDark() {
+        var theme = chosenTheme || defaultTheme;
+        if (theme === 'system') {
+            return !!(media && media.matches);
+        }
+        return theme === 'dark';
+    }
+
+    function apply() {
+        var dark = isDark();
+        document.documentElement.classList.toggle(DARK_CLASS, dark);
+
+        var toggle = document.querySelector('.' + TOGGLE_CLASS);
+        if (toggle) {
+            toggle.textContent = dark ? '☀' : '☾';
+            toggle.setAttribute('aria-pressed', String(dark));
+        }
+    }
+
+    function toggleTheme() {
+        chosenTheme = isDark() ? 'light' : 'dark';
+        try {
+            window.localStorage.setItem(STORAGE_KEY, chosenTheme);
+        } catch (e) {
+            // the choice just won't be remembered across page loads
+        }
+        apply();
+    }
+
+    function addToggle() {
+        if (document.querySelector('.' + TOGGLE_CLASS)) {
+            return true;
+        }
+        var topbar = document.querySelector('.swagger-ui .topbar-wrapper');
+        if (!topbar) {
+            return false;
+        }
+
+        var toggle = document.createElement('button');
+        toggle.type = 'button';
+        toggle.className = TOGGLE_CLASS;
+        toggle.title = 'Toggle dark theme';
+        toggle.setAttribute('aria-label', 'Toggle dark theme');
+        toggle.addEventListener('click', toggleTheme);
+        topbar.appendChild(toggle);
+        apply();
+
+        return true;
+    }
+
+    if (media) {
+        if (media.addEventListener) {
+            media.addEventListener('change', apply);
+        } else if (media.addListener) {
+            media.addListener(apply);
+        }
+    }
+    apply();
+
+    if (!addToggle()) {
+        var observer = new MutationObserver(function () {
+            if (addToggle()) {
+                observer.disconnect();
+            }
+        });
+        observer.observe(document.documentElement, { childList: true, subtree: true });
+    }
+})();
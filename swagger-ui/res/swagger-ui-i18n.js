@@ -0,0 +1,105 @@
+/*
+ * Translation of the Swagger UI labels, maintained as part of
+ * swagger-ui-redist.
+ *
+ * Reads the dictionary from the `swagger-ui-translation` JSON script and
+ * replaces the labels rendered by Swagger UI as they appear in the page. Only
+ * whole labels are translated, and the content coming from the spec (the
+ * descriptions, names and examples) is left untouched.
+ */
+(function () {
+    'use strict';
+
+    var source = document.getElementById('swagger-ui-translation');
+    if (!source) {
+        return;
+    }
+
+    var dictionary = JSON.parse(source.textContent);
+    var ATTRIBUTES = ['placeholder', 'title', 'aria-label'];
+    var SKIPPED = [
+        'script', 'style', 'pre', 'code', 'textarea', 'input',
+        '.info', '.markdown', '.renderedMarkdown', '.microlight', '.highlight-code',
+        '.opblock-summary-path', '.opblock-summary-description',
+        '.parameter__name', '.prop-name', '.model-title'
+    ].join(', ');
+
+    function lookup(text) {
+        var label = text.trim();
+        if (label && Object.prototype.hasOwnProperty.call(dictionary, label)) {
+            return text.replace(label, dictionary[label]);
+        }
+        return null;
+    }
+
+    function isSkipped(element) {
+        return !!(element && element.closest && element.closest(SKIPPED));
+    }
+
+    function translateText(node) {
+        if (isSkipped(node.parentElement)) {
+            return;
+        }
+        var translated = lookup(node.nodeValue);
+        if (translated !== null) {
+            node.nodeValue = translated;
+        }
+    }
+
+    function translateAttributes(element) {
+        ATTRIBUTES.forEach(function (attribute) {
+            var value = element.getAttribute(attribute);
+            var translated = value === null ? null : lookup(value);
+            if (translated !== null) {
+                element.setAttribute(attribute, translated);
+            }
+        });
+    }
+
+    function translate(root) {
+        if (root.nodeType === Node.TEXT_NODE) {
+            translateText(root);
+            return;
+        }
+        if (root.nodeType !== Node.ELEMENT_NODE || isSkipped(root)) {
+            return;
+        }
+
+        translateAttributes(root);
+        var walker = document.createTreeWalker(root, NodeFilter.SHOW_ELEMENT | NodeFilter.SHOW_TEXT, {
+            acceptNode: function (node) {
+                if (node.nodeType === Node.ELEMENT_NODE && node.matches(SKIPPED)) {
+                    return NodeFilter.FILTER_REJECT;
+                }
+                return NodeFilter.FILTER_ACCEPT;
+            }
+        });
+        while (walker.nextNode()) {
+            if (walker.currentNode.nodeType === Node.TEXT_NODE) {
+                translateText(walker.currentNode);
+            } else {
+                translateAttributes(walker.currentNode);
+            }
+        }
+    }
+
+    new MutationObserver(function (mutations) {
+        mutations.forEach(function (mutation) {
+            if (mutation.type === 'characterData') {
+                translateText(mutation.target);
+            } else if (mutation.type === 'attributes') {
+                translateAttributes(mutation.target);
+            } else {
+                mutation.addedNodes.forEach(translate);
+            }
+        });
+    }).observe(document.documentElement, {
+        childList: true,
+        subtree: true,
+        characterData: true,
+        attributes: true,
+        attributeFilter: ATTRIBUTES
+    });
+
+    translate(document.body);
+})();
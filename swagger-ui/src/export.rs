@@ -0,0 +1,395 @@
+//! Exporting Swagger UI as a static site.
+//!
+//! See [`SwaggerUi::export_to_dir`] for more details.
+
+use std::error::Error;
+use std::path::{Component, Path, PathBuf};
+use std::{fs, io};
+
+use crate::{SwaggerUi, SwaggerUiStaticFile};
+
+/// Name of the config file written by [`SwaggerUi::export_to_dir`].
+///
+/// The file contains the Swagger UI configuration in the format expected by
+/// [`Config::config_url`](crate::Config::config_url).
+pub const CONFIG_FILE_NAME: &str = "swagger-config.json";
+
+/// Options for exporting Swagger UI with [`SwaggerUi::export_to_dir_with`].
+///
+/// # Examples
+///
+/// ```
+/// # use swagger_ui_redist::export::ExportOptions;
+/// let mut options = ExportOptions::new();
+/// options.spec(
+///     "/api-docs/openapi.json",
+///     br#"{"openapi": "3.1.0"}"#.to_vec(),
+/// );
+/// ```
+#[non_exhaustive]
+#[derive(Debug, Default, Clone)]
+pub struct ExportOptions {
+    specs: Vec<(String, Vec<u8>)>,
+    #[cfg(feature = "gzip")]
+    gzip: bool,
+    #[cfg(feature = "brotli")]
+    brotli: bool,
+}
+
+impl ExportOptions {
+    /// Creates new [`ExportOptions`] with default settings.
+    ///
+    /// By default, no OpenAPI specs are written and no precompressed files are
+    /// generated.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds an OpenAPI spec to be written along with Swagger UI.
+    ///
+    /// The path should be the same as the URL the spec is referenced by in the
+    /// [`Config`](crate::Config) (e.g. `/api-docs/openapi.json`); it is
+    /// interpreted relative to the export directory. The path must not
+    /// contain any `..` components.
+    ///
+    /// Note that for the spec to be found by Swagger UI when the directory
+    /// is served under a prefix, the URL in the config should be relative as
+    /// well (e.g. `./api-docs/openapi.json`).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use swagger_ui_redist::export::ExportOptions;
+    /// let mut options = ExportOptions::new();
+    /// options
+    ///     .spec("./openapi-v1.json", b"{}".to_vec())
+    ///     .spec("./openapi-v2.json", b"{}".to_vec());
+    /// ```
+    pub fn spec<S: Into<String>>(&mut self, path: S, content: Vec<u8>) -> &mut Self {
+        self.specs.push((path.into(), content));
+
+        self
+    }
+
+    /// Set to `true` to write a gzip-compressed `.gz` sibling for every text
+    /// file, e.g. for use with nginx `gzip_static` directive.
+    ///
+    /// Default value is `false`.
+    #[cfg(feature = "gzip")]
+    pub fn gzip(&mut self, gzip: bool) -> &mut Self {
+        self.gzip = gzip;
+
+        self
+    }
+
+    /// Set to `true` to write a brotli-compressed `.br` sibling for every text
+    /// file, e.g. for use with nginx `brotli_static` directive.
+    ///
+    /// Default value is `false`.
+    #[cfg(feature = "brotli")]
+    pub fn brotli(&mut self, brotli: bool) -> &mut Self {
+        self.brotli = brotli;
+
+        self
+    }
+
+    #[cfg_attr(
+        not(any(feature = "gzip", feature = "brotli")),
+        expect(
+            unused_mut,
+            unused_variables,
+            clippy::unused_self,
+            clippy::unnecessary_wraps,
+            reason = "only used when compression is enabled"
+        )
+    )]
+    fn precompress(&self, content: &[u8]) -> io::Result<Vec<(&'static str, Vec<u8>)>> {
+        let mut compressed = Vec::new();
+
+        #[cfg(feature = "gzip")]
+        if self.gzip {
+            compressed.push(("gz", gzip(content)?));
+        }
+        #[cfg(feature = "brotli")]
+        if self.brotli {
+            compressed.push(("br", brotli(content)?));
+        }
+
+        Ok(compressed)
+    }
+}
+
+impl SwaggerUi {
+    /// Exports Swagger UI as a static site to given directory.
+    ///
+    /// This writes the following files, creating the directory if needed:
+    /// * `index.html` - the page generated by [`SwaggerUi::serve`],
+    /// * every [`SwaggerUiStaticFile`] (including the OAuth2 redirect page), at
+    ///   its (possibly overridden) relative path. Files whose path has been
+    ///   overridden with an absolute path or a URL are skipped,
+    /// * [`CONFIG_FILE_NAME`] - the Swagger UI configuration as JSON.
+    ///
+    /// Existing files are overwritten. The resulting directory can be served
+    /// by any static file server (e.g. nginx or an S3 bucket).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the Swagger UI config fails to be serialized or if
+    /// any of the files could not be written.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use swagger_ui_redist::SwaggerUi;
+    /// let mut swagger = SwaggerUi::new();
+    /// swagger.config().urls(["./openapi.json"]);
+    /// swagger.export_to_dir("target/docs")?;
+    /// # Ok::<(), Box<dyn std::error::Error + Send + Sync>>(())
+    /// ```
+    pub fn export_to_dir<P: AsRef<Path>>(
+        &self,
+        path: P,
+    ) -> Result<(), Box<dyn Error + Send + Sync>> {
+        self.export_to_dir_with(path, &ExportOptions::default())
+    }
+
+    /// Exports Swagger UI as a static site to given directory, with given
+    /// options.
+    ///
+    /// This works just like [`SwaggerUi::export_to_dir`], but additionally
+    /// writes the OpenAPI specs and the precompressed files, as configured in
+    /// given [`ExportOptions`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the Swagger UI config fails to be serialized, if
+    /// any of the spec paths is invalid, or if any of the files could not be
+    /// written.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use swagger_ui_redist::SwaggerUi;
+    /// # use swagger_ui_redist::export::ExportOptions;
+    /// let mut swagger = SwaggerUi::new();
+    /// swagger.config().urls(["./openapi.json"]);
+    ///
+    /// let mut options = ExportOptions::new();
+    /// options.spec("./openapi.json", std::fs::read("openapi.json")?);
+    /// swagger.export_to_dir_with("target/docs", &options)?;
+    /// # Ok::<(), Box<dyn std::error::Error + Send + Sync>>(())
+    /// ```
+    pub fn export_to_dir_with<P: AsRef<Path>>(
+        &self,
+        path: P,
+        options: &ExportOptions,
+    ) -> Result<(), Box<dyn Error + Send + Sync>> {
+        let path = path.as_ref();
+        let exporter = Exporter { path, options };
+
+        exporter.write("index.html", self.serve()?.as_bytes(), true)?;
+        for static_file in SwaggerUiStaticFile::all() {
+            if let Some(file_path) = self.file_paths.relative(*static_file) {
+                let compress = !static_file.content_type().starts_with("image/");
+                exporter.write(file_path, static_file.bytes(), compress)?;
+            }
+        }
+        for (name, image) in [("favicon", &self.favicon), ("logo", &self.logo)] {
+            if let Some(image) = image {
+                exporter.write(&image.file_name(name), &image.bytes, false)?;
+            }
+        }
+        for asset in &self.custom_assets {
+            exporter.write(&asset.file_name, &asset.content, true)?;
+        }
+        exporter.write(
+            CONFIG_FILE_NAME,
+            self.config_format.to_json(&self.config)?.as_bytes(),
+            true,
+        )?;
+        for (spec_path, content) in &options.specs {
+            exporter.write(spec_path, content, true)?;
+        }
+
+        Ok(())
+    }
+}
+
+#[derive(Debug)]
+struct Exporter<'a> {
+    path: &'a Path,
+    options: &'a ExportOptions,
+}
+
+impl Exporter<'_> {
+    fn write(&self, file_path: &str, content: &[u8], compress: bool) -> io::Result<()> {
+        let target = self.target_path(file_path)?;
+        if let Some(parent) = target.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        fs::write(&target, content)?;
+
+        if compress {
+            for (extension, compressed) in self.options.precompress(content)? {
+                fs::write(with_extension(&target, extension), compressed)?;
+            }
+        }
+
+        Ok(())
+    }
+
+    fn target_path(&self, file_path: &str) -> io::Result<PathBuf> {
+        let relative_path = Path::new(file_path.trim_start_matches('/'));
+        let mut target = self.path.to_path_buf();
+
+        for component in relative_path.components() {
+            match component {
+                Component::Normal(part) => target.push(part),
+                Component::CurDir => {}
+                Component::ParentDir | Component::RootDir | Component::Prefix(_) => {
+                    return Err(io::Error::new(
+                        io::ErrorKind::InvalidInput,
+                        format!("invalid export path: {file_path}"),
+                    ));
+                }
+            }
+        }
+
+        if target == self.path {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!("invalid export path: {file_path}"),
+            ));
+        }
+
+        Ok(target)
+    }
+}
+
+fn with_extension(path: &Path, extension: &str) -> PathBuf {
+    let mut path = path.as_os_str().to_owned();
+    path.push(".");
+    path.push(extension);
+
+    PathBuf::from(path)
+}
+
+#[cfg(feature = "gzip")]
+fn gzip(content: &[u8]) -> io::Result<Vec<u8>> {
+    use std::io::Write;
+
+    let mut encoder = flate2::write::GzEncoder::new(Vec::new(), flate2::Compression::best());
+    encoder.write_all(content)?;
+    encoder.finish()
+}
+
+#[cfg(feature = "brotli")]
+fn brotli(content: &[u8]) -> io::Result<Vec<u8>> {
+    use std::io::Write;
+
+    const BUFFER_SIZE: usize = 4096;
+    const QUALITY: u32 = 11;
+    const WINDOW_SIZE: u32 = 22;
+
+    let mut compressed = Vec::new();
+    {
+        let mut encoder =
+            brotli::CompressorWriter::new(&mut compressed, BUFFER_SIZE, QUALITY, WINDOW_SIZE);
+        encoder.write_all(content)?;
+    }
+
+    Ok(compressed)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn export_dir(name: &str) -> PathBuf {
+        let path =
+            std::env::temp_dir().join(format!("swagger-ui-redist-{}-{name}", std::process::id()));
+        let _ = fs::remove_dir_all(&path);
+
+        path
+    }
+
+    #[test]
+    fn export_writes_all_files() {
+        let path = export_dir("all-files");
+        let mut swagger = SwaggerUi::new();
+        swagger.config().urls(["./openapi.json"]);
+
+        swagger.export_to_dir(&path).unwrap();
+
+        assert_eq!(
+            fs::read_to_string(path.join("index.html")).unwrap(),
+            swagger.serve().unwrap()
+        );
+        for static_file in SwaggerUiStaticFile::all() {
+            assert_eq!(
+                fs::read(path.join(static_file.file_name())).unwrap(),
+                static_file.bytes()
+            );
+        }
+        let config = fs::read_to_string(path.join(CONFIG_FILE_NAME)).unwrap();
+        assert!(config.contains(r#""url": "./openapi.json""#));
+
+        fs::remove_dir_all(&path).unwrap();
+    }
+
+    #[test]
+    fn export_writes_overridden_paths_and_specs() {
+        let path = export_dir("overridden");
+        let mut swagger = SwaggerUi::new();
+        swagger.config().urls(["./specs/openapi.json"]);
+        swagger.override_file_path(SwaggerUiStaticFile::Css, "./assets/ui.css".to_string());
+        swagger.override_file_path(
+            SwaggerUiStaticFile::Js,
+            "https://cdn.example.com/swagger-ui-bundle.js".to_string(),
+        );
+        let mut options = ExportOptions::new();
+        options.spec("./specs/openapi.json", b"{}".to_vec());
+
+        swagger.export_to_dir_with(&path, &options).unwrap();
+
+        assert!(path.join("assets/ui.css").is_file());
+        assert!(!path.join("swagger-ui.css").exists());
+        assert!(!path.join("swagger-ui-bundle.js").exists());
+        assert_eq!(fs::read(path.join("specs/openapi.json")).unwrap(), b"{}");
+
+        fs::remove_dir_all(&path).unwrap();
+    }
+
+    #[test]
+    fn export_rejects_parent_dir_spec_path() {
+        let path = export_dir("parent-dir");
+        let mut options = ExportOptions::new();
+        options.spec("../openapi.json", b"{}".to_vec());
+
+        let result = SwaggerUi::new().export_to_dir_with(&path, &options);
+
+        assert!(result.is_err());
+        let _ = fs::remove_dir_all(&path);
+    }
+
+    #[cfg(all(feature = "gzip", feature = "brotli"))]
+    #[test]
+    fn export_writes_precompressed_files() {
+        let path = export_dir("precompressed");
+        let mut options = ExportOptions::new();
+        options.gzip(true).brotli(true);
+
+        SwaggerUi::new()
+            .export_to_dir_with(&path, &options)
+            .unwrap();
+
+        assert!(path.join("index.html.gz").is_file());
+        assert!(path.join("index.html.br").is_file());
+        assert!(path.join("swagger-ui-bundle.js.gz").is_file());
+        assert!(path.join("swagger-ui-bundle.js.br").is_file());
+        assert!(!path.join("favicon-16x16.png.gz").exists());
+
+        fs::remove_dir_all(&path).unwrap();
+    }
+}
@@ -0,0 +1,192 @@
+//! Swagger UI with the page rendered once.
+//!
+//! See [`SwaggerUi::freeze`] for more details.
+
+use std::borrow::Cow;
+use std::error::Error;
+use std::sync::Arc;
+
+use openapi_gui_core::{INDEX_CONTENT_TYPE, is_index_path};
+
+use crate::{SwaggerFile, SwaggerUi, etag};
+
+impl SwaggerUi {
+    /// Renders the Swagger UI page once, returning a [`FrozenSwaggerUi`] which
+    /// serves it without rendering it again.
+    ///
+    /// [`SwaggerUi::serve`] serializes the [`Config`](crate::Config) and
+    /// renders the page on every call, which is a waste when the page is
+    /// always the same. A [`FrozenSwaggerUi`] hands out the rendered page,
+    /// along with its entity tag, as reference-counted clones instead, so it
+    /// is cheap to serve on every request. Since the [`SwaggerUi`] can't be
+    /// modified after being frozen, the page never gets out of date.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the Swagger UI config is invalid (see
+    /// [`InvalidConfigError`](crate::InvalidConfigError)) or fails to be
+    /// serialized.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use swagger_ui_redist::SwaggerUi;
+    /// let mut swagger = SwaggerUi::new();
+    /// swagger.config().urls(["/api-docs/openapi.json"]);
+    /// let html = swagger.serve()?;
+    ///
+    /// let frozen = swagger.freeze()?;
+    /// assert_eq!(*frozen.index(), html);
+    /// # Ok::<(), Box<dyn std::error::Error + Send + Sync>>(())
+    /// ```
+    pub fn freeze(self) -> Result<FrozenSwaggerUi, Box<dyn Error + Send + Sync>> {
+        let index = self.serve()?;
+
+        Ok(FrozenSwaggerUi {
+            etag: etag(index.as_bytes()).into(),
+            index: index.into(),
+            swagger_ui: Arc::new(self),
+        })
+    }
+}
+
+/// [`SwaggerUi`] with the page rendered once, created with
+/// [`SwaggerUi::freeze`].
+///
+/// Cloning is cheap, as the [`SwaggerUi`] instance and the rendered page are
+/// shared between the clones.
+#[derive(Debug, Clone)]
+pub struct FrozenSwaggerUi {
+    swagger_ui: Arc<SwaggerUi>,
+    index: Arc<str>,
+    etag: Arc<str>,
+}
+
+impl FrozenSwaggerUi {
+    /// Returns the [`SwaggerUi`] the page has been rendered from.
+    #[must_use]
+    pub fn swagger_ui(&self) -> &SwaggerUi {
+        &self.swagger_ui
+    }
+
+    /// Returns the rendered HTML page, the same as returned by
+    /// [`SwaggerUi::serve`].
+    #[must_use]
+    pub fn index(&self) -> Arc<str> {
+        Arc::clone(&self.index)
+    }
+
+    /// Returns the rendered HTML page as [`Bytes`](bytes::Bytes), sharing the
+    /// page rather than copying it.
+    ///
+    /// Only available with the `bytes` feature.
+    #[cfg(feature = "bytes")]
+    #[must_use]
+    pub fn index_bytes(&self) -> bytes::Bytes {
+        bytes::Bytes::from_owner(IndexBytes(self.index()))
+    }
+
+    /// Returns the strong entity tag of the rendered page, including the
+    /// surrounding double quotes (e.g. `"5d41402abc4b2a76"`).
+    #[must_use]
+    pub fn etag(&self) -> &str {
+        &self.etag
+    }
+
+    /// Looks up the file that should be served under the given path, just like
+    /// [`SwaggerUi::file`], without rendering the page again.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use swagger_ui_redist::SwaggerUi;
+    /// let frozen = SwaggerUi::new().freeze()?;
+    ///
+    /// let index = frozen.file("/").expect("index should be present");
+    /// assert_eq!(index.etag, frozen.etag());
+    /// assert!(frozen.file("/swagger-ui.css").is_some());
+    /// # Ok::<(), Box<dyn std::error::Error + Send + Sync>>(())
+    /// ```
+    #[must_use]
+    pub fn file(&self, path: &str) -> Option<SwaggerFile<'_>> {
+        if is_index_path(path) {
+            return Some(SwaggerFile {
+                bytes: Cow::Borrowed(self.index.as_bytes()),
+                content_type: INDEX_CONTENT_TYPE.to_string(),
+                etag: self.etag.to_string(),
+            });
+        }
+
+        self.swagger_ui.asset_file(path)
+    }
+}
+
+/// The rendered page as the owner of [`Bytes`](bytes::Bytes).
+#[cfg(feature = "bytes")]
+struct IndexBytes(Arc<str>);
+
+#[cfg(feature = "bytes")]
+impl AsRef<[u8]> for IndexBytes {
+    fn as_ref(&self) -> &[u8] {
+        self.0.as_bytes()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Theme;
+
+    #[test]
+    fn serves_the_same_files() {
+        let mut swagger = SwaggerUi::new();
+        swagger
+            .theme(Theme::Dark)
+            .add_script("custom.js", b"console.log(1);".as_slice())
+            .config()
+            .urls(["/api-docs/openapi.json"]);
+        let expected = swagger.clone();
+
+        let frozen = swagger.freeze().unwrap();
+
+        for path in ["", "/", "/index.html", "/swagger-ui.css", "/custom.js"] {
+            let file = frozen.file(path).unwrap();
+            let expected = expected.file(path).unwrap().unwrap();
+            assert_eq!(file.bytes, expected.bytes, "{path}");
+            assert_eq!(file.content_type, expected.content_type, "{path}");
+            assert_eq!(file.etag, expected.etag, "{path}");
+        }
+        assert!(frozen.file("/does-not-exist.js").is_none());
+    }
+
+    #[test]
+    fn clones_share_the_page() {
+        let frozen = SwaggerUi::new().freeze().unwrap();
+        let cloned = frozen.clone();
+
+        assert!(Arc::ptr_eq(&frozen.index(), &cloned.index()));
+        assert_eq!(frozen.etag(), etag(frozen.index().as_bytes()));
+    }
+
+    #[test]
+    fn invalid_config() {
+        let mut swagger = SwaggerUi::new();
+        swagger.config().urls([
+            crate::Url::from("/a.json").primary(true),
+            crate::Url::from("/b.json").primary(true),
+        ]);
+
+        assert!(swagger.freeze().is_err());
+    }
+
+    #[cfg(feature = "bytes")]
+    #[test]
+    fn index_bytes() {
+        let frozen = SwaggerUi::new().freeze().unwrap();
+
+        let bytes = frozen.index_bytes();
+
+        assert_eq!(bytes, frozen.index().as_bytes());
+        assert_eq!(bytes.as_ptr(), frozen.index().as_ptr());
+    }
+}
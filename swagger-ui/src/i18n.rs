@@ -0,0 +1,162 @@
+//! Localization of the labels displayed by Swagger UI.
+//!
+//! Swagger UI itself is only available in English. A [`Translation`] is a
+//! dictionary mapping its English labels (such as "Try it out", "Execute" or
+//! "Authorize") to their translations, which is applied by
+//! [`SwaggerUiStaticFile::TranslationJs`] as Swagger UI renders the page.
+//! Only whole labels are translated, and the content coming from the OpenAPI
+//! spec (descriptions, names, examples) is left untouched.
+//!
+//! Some translations are bundled with the crate, behind cargo features:
+//!
+//! * `i18n-de` - `Translation::german`
+//! * `i18n-ja` - `Translation::japanese`
+//!
+//! [`SwaggerUiStaticFile::TranslationJs`]: crate::SwaggerUiStaticFile::TranslationJs
+
+use std::collections::BTreeMap;
+use std::error::Error;
+
+/// Dictionary translating the labels of Swagger UI, set with
+/// [`SwaggerUi::translation`](crate::SwaggerUi::translation).
+///
+/// # Examples
+///
+/// ```
+/// # use swagger_ui_redist::SwaggerUi;
+/// # use swagger_ui_redist::i18n::Translation;
+/// let mut translation = Translation::new();
+/// translation
+///     .label("Try it out", "Essayer")
+///     .label("Execute", "Exécuter");
+///
+/// let mut swagger = SwaggerUi::new();
+/// swagger.lang("fr").translation(translation);
+/// ```
+#[non_exhaustive]
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct Translation {
+    labels: BTreeMap<String, String>,
+}
+
+impl Translation {
+    /// Creates a new, empty [`Translation`].
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Parses a [`Translation`] from a JSON object mapping the English labels
+    /// to their translations.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the JSON is invalid or is not an object of
+    /// strings.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use swagger_ui_redist::i18n::Translation;
+    /// let translation = Translation::from_json(r#"{"Execute": "Exécuter"}"#)?;
+    /// # Ok::<(), Box<dyn std::error::Error + Send + Sync>>(())
+    /// ```
+    pub fn from_json(json: &str) -> Result<Self, Box<dyn Error + Send + Sync>> {
+        Ok(Self {
+            labels: serde_json::from_str(json)?,
+        })
+    }
+
+    /// German translation of the Swagger UI labels.
+    #[cfg(feature = "i18n-de")]
+    #[must_use]
+    pub fn german() -> Self {
+        Self::bundled(include_str!("../res/i18n/de.json"))
+    }
+
+    /// Japanese translation of the Swagger UI labels.
+    #[cfg(feature = "i18n-ja")]
+    #[must_use]
+    pub fn japanese() -> Self {
+        Self::bundled(include_str!("../res/i18n/ja.json"))
+    }
+
+    #[cfg(any(feature = "i18n-de", feature = "i18n-ja"))]
+    fn bundled(json: &str) -> Self {
+        Self::from_json(json).expect("bundled translation should be valid")
+    }
+
+    /// Adds a translation of given English label, replacing the previous one,
+    /// if any.
+    pub fn label(
+        &mut self,
+        english: impl Into<String>,
+        translated: impl Into<String>,
+    ) -> &mut Self {
+        self.labels.insert(english.into(), translated.into());
+        self
+    }
+
+    /// Returns the translation of given English label, if there is one.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use swagger_ui_redist::i18n::Translation;
+    /// let mut translation = Translation::new();
+    /// translation.label("Execute", "Ausführen");
+    ///
+    /// assert_eq!(translation.get("Execute"), Some("Ausführen"));
+    /// assert_eq!(translation.get("Cancel"), None);
+    /// ```
+    #[must_use]
+    pub fn get(&self, english: &str) -> Option<&str> {
+        self.labels.get(english).map(String::as_str)
+    }
+
+    /// Serializes the dictionary to JSON which can be embedded in a `<script>`
+    /// element.
+    pub(crate) fn to_script_json(&self) -> String {
+        self.labels
+            .iter()
+            .map(|(english, translated)| {
+                (
+                    english.clone(),
+                    serde_json::Value::from(translated.as_str()),
+                )
+            })
+            .collect::<serde_json::Value>()
+            .to_string()
+            .replace("</", r"<\/")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn to_script_json_escapes_script_end() {
+        let mut translation = Translation::new();
+        translation.label("Execute", "</script>");
+
+        assert_eq!(translation.to_script_json(), r#"{"Execute":"<\/script>"}"#);
+    }
+
+    #[test]
+    fn from_json_invalid() {
+        assert!(Translation::from_json(r#"{"Execute": 1}"#).is_err());
+    }
+
+    #[cfg(feature = "i18n-de")]
+    #[test]
+    fn german() {
+        assert_eq!(Translation::german().get("Execute"), Some("Ausführen"));
+    }
+
+    #[cfg(feature = "i18n-ja")]
+    #[test]
+    fn japanese() {
+        assert_eq!(Translation::japanese().get("Execute"), Some("実行"));
+    }
+}
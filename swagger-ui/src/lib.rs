@@ -11,6 +11,36 @@
 //! It was mainly created to be integrated inside the [Cot web framework](https://cot.rs/),
 //! but does not depend on it. It can be used with any web framework.
 //!
+//! # Feature flags
+//!
+//! * `v5` (enabled by default) - bundles Swagger UI 5.
+//! * `v4` - bundles Swagger UI 4 instead, for tooling and plugins that have not
+//!   been ported to Swagger UI 5 yet.
+//! * `i18n-de`, `i18n-ja` - enable the bundled German and Japanese translations
+//!   of the Swagger UI labels, see the [`i18n`] module.
+//! * `bytes` - enables sharing the page rendered once by [`SwaggerUi::freeze`]
+//!   as `bytes::Bytes`.
+//! * `tower` - enables `service::SwaggerUiService`, a `tower::Service`
+//!   implementation that can be mounted in any `tower`-based framework.
+//! * `gzip` - enables writing gzip-compressed files when exporting Swagger UI
+//!   with [`SwaggerUi::export_to_dir_with`].
+//! * `brotli` - enables writing brotli-compressed files when exporting Swagger
+//!   UI with [`SwaggerUi::export_to_dir_with`].
+//!
+//! Only one version of Swagger UI is ever embedded in the binary, so the `v4`
+//! and `v5` features are meant to be mutually exclusive. To use Swagger UI 4,
+//! disable the default features:
+//!
+//! ```toml
+//! swagger-ui-redist = { version = "0.1", default-features = false, features = ["v4"] }
+//! ```
+//!
+//! The features are not enforced to be exclusive, so that the crate still
+//! builds when both of them end up enabled (for instance because of feature
+//! unification or `--all-features`); `v4` takes precedence then. If neither of
+//! them is enabled, the crate fails to compile. The version actually bundled
+//! is available as [`SwaggerUi::VERSION`].
+//!
 //! # Attribution
 //!
 //! This crate is heavily based on [`utoipa-swagger-ui`](https://github.com/juhaku/utoipa),
@@ -25,11 +55,54 @@
 use std::borrow::Cow;
 use std::collections::HashMap;
 use std::error::Error;
-use std::mem;
+use std::fmt::{self, Display, Formatter};
+use std::sync::OnceLock;
+
+use openapi_gui_core::{
+    FilePaths, GuiFile, INDEX_CONTENT_TYPE, OpenApiGui, SpecUrl, StaticFile, escape_html,
+    is_index_path,
+};
 
+pub mod export;
+pub mod frozen;
+pub mod i18n;
 pub mod oauth;
+pub mod range;
+#[cfg(feature = "tower")]
+pub mod service;
+pub mod template;
 
+use i18n::Translation;
 use serde::Serialize;
+use template::Template;
+
+#[cfg(not(any(feature = "v4", feature = "v5")))]
+compile_error!(
+    "either the `v4` or the `v5` feature has to be enabled to select the Swagger UI version"
+);
+
+/// The directory containing the files of the bundled Swagger UI version.
+#[cfg(feature = "v4")]
+macro_rules! res_dir {
+    () => {
+        "../res/v4/"
+    };
+}
+
+/// The directory containing the files of the bundled Swagger UI version.
+#[cfg(all(feature = "v5", not(feature = "v4")))]
+macro_rules! res_dir {
+    () => {
+        "../res/v5/"
+    };
+}
+
+/// Includes the bytes of a file of the bundled Swagger UI version.
+macro_rules! res {
+    ($file:literal) => {
+        include_bytes!(concat!(res_dir!(), $file))
+    };
+}
 
 /// Entry point for serving Swagger UI and api docs in application. It provides
 /// builder style chainable configuration methods for configuring api doc urls.
@@ -48,8 +121,17 @@ use serde::Serialize;
 #[derive(Debug, Clone)]
 pub struct SwaggerUi {
     title: Cow<'static, str>,
-    config: Config<'static>,
-    file_paths: HashMap<SwaggerUiStaticFile, String>,
+    config: Config,
+    file_paths: FilePaths<SwaggerUiStaticFile>,
+    template: Template,
+    custom_assets: Vec<CustomAsset>,
+    theme: Theme,
+    favicon: Option<Image>,
+    logo: Option<Image>,
+    logo_link: Option<String>,
+    lang: Cow<'static, str>,
+    translation: Option<Translation>,
+    config_format: ConfigFormat,
 }
 
 impl Default for SwaggerUi {
@@ -59,6 +141,31 @@ impl Default for SwaggerUi {
 }
 
 impl SwaggerUi {
+    /// The version of Swagger UI bundled in this crate, selected with the `v4`
+    /// and `v5` cargo features.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use swagger_ui_redist::SwaggerUi;
+    /// println!("Powered by Swagger UI {}", SwaggerUi::VERSION);
+    /// ```
+    pub const VERSION: &'static str = include_str!(concat!(res_dir!(), "VERSION"));
+
+    /// The license of the bundled Swagger UI (Apache License 2.0), as
+    /// published upstream.
+    ///
+    /// The same text is served as [`SwaggerUiStaticFile::License`], so that
+    /// the attribution can be linked from the page.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use swagger_ui_redist::SwaggerUi;
+    /// assert!(SwaggerUi::LICENSE.contains("Apache License"));
+    /// ```
+    pub const LICENSE: &'static str = include_str!(concat!(res_dir!(), "LICENSE"));
+
     /// Create a new [`SwaggerUi`] for given path.
     ///
     /// Path argument will expose the Swagger UI to the user and should be
@@ -79,7 +186,16 @@ impl SwaggerUi {
         Self {
             title: Cow::Borrowed("Swagger UI"),
             config: Config::new(),
-            file_paths: SwaggerUiStaticFile::default_map(),
+            file_paths: FilePaths::new(),
+            template: Template::new(),
+            custom_assets: Vec::new(),
+            theme: Theme::default(),
+            favicon: None,
+            logo: None,
+            logo_link: None,
+            lang: Cow::Borrowed("en"),
+            translation: None,
+            config_format: ConfigFormat::default(),
         }
     }
 
@@ -99,13 +215,32 @@ impl SwaggerUi {
     ///     .try_it_out_enabled(true)
     ///     .filter(true);
     /// ```
-    pub fn config(&mut self) -> &mut Config<'static> {
+    pub fn config(&mut self) -> &mut Config {
         &mut self.config
     }
 
+    /// Return a mutable reference to the HTML template of the page, allowing
+    /// to modify it.
+    ///
+    /// See [`Template`] for more details.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use swagger_ui_redist::SwaggerUi;
+    /// let mut swagger = SwaggerUi::new();
+    /// swagger
+    ///     .template()
+    ///     .body_start(r#"<div class="banner">Staging environment</div>"#);
+    /// ```
+    pub fn template(&mut self) -> &mut Template {
+        &mut self.template
+    }
+
     /// Sets the title for the Swagger UI page.
     ///
-    /// The title will be displayed in the browser tab.
+    /// The title will be displayed in the browser tab. It is HTML-escaped when
+    /// the page is rendered, so it doesn't need to be escaped beforehand.
     ///
     /// # Examples
     ///
@@ -133,29 +268,31 @@ impl SwaggerUi {
     #[must_use]
     pub fn static_files() -> &'static [(SwaggerUiStaticFile, &'static [u8])] {
         &[
+            (SwaggerUiStaticFile::Css, res!("swagger-ui.css")),
+            (SwaggerUiStaticFile::IndexCss, res!("index.css")),
+            (SwaggerUiStaticFile::Js, res!("swagger-ui-bundle.js")),
             (
-                SwaggerUiStaticFile::Css,
-                include_bytes!("../res/swagger-ui.css"),
-            ),
-            (
-                SwaggerUiStaticFile::IndexCss,
-                include_bytes!("../res/index.css"),
+                SwaggerUiStaticFile::StandalonePresetJs,
+                res!("swagger-ui-standalone-preset.js"),
             ),
+            (SwaggerUiStaticFile::Favicon16, res!("favicon-16x16.png")),
+            (SwaggerUiStaticFile::Favicon32, res!("favicon-32x32.png")),
             (
-                SwaggerUiStaticFile::Js,
-                include_bytes!("../res/swagger-ui-bundle.js"),
+                SwaggerUiStaticFile::OAuth2Redirect,
+                res!("oauth2-redirect.html"),
             ),
+            (SwaggerUiStaticFile::License, res!("LICENSE")),
             (
-                SwaggerUiStaticFile::StandalonePresetJs,
-                include_bytes!("../res/swagger-ui-standalone-preset.js"),
+                SwaggerUiStaticFile::DarkThemeCss,
+                include_bytes!("../res/swagger-ui-dark.css"),
             ),
             (
-                SwaggerUiStaticFile::Favicon16,
-                include_bytes!("../res/favicon-16x16.png"),
+                SwaggerUiStaticFile::DarkThemeJs,
+                include_bytes!("../res/swagger-ui-dark.js"),
             ),
             (
-                SwaggerUiStaticFile::Favicon32,
-                include_bytes!("../res/favicon-32x32.png"),
+                SwaggerUiStaticFile::TranslationJs,
+                include_bytes!("../res/swagger-ui-i18n.js"),
             ),
         ]
     }
@@ -183,7 +320,334 @@ impl SwaggerUi {
     /// );
     /// ```
     pub fn override_file_path(&mut self, static_file: SwaggerUiStaticFile, path: String) {
-        self.file_paths.insert(static_file, path);
+        self.file_paths.set(static_file, path);
+    }
+
+    /// Sets the color theme of the Swagger UI page.
+    ///
+    /// Swagger UI only comes with a light theme. With [`Theme::System`] or
+    /// [`Theme::Dark`], the page additionally includes
+    /// [`SwaggerUiStaticFile::DarkThemeCss`] and
+    /// [`SwaggerUiStaticFile::DarkThemeJs`], which add a button to the top
+    /// bar switching between the light and the dark theme. The choice made by
+    /// the user is remembered in the browser's local storage.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use swagger_ui_redist::{SwaggerUi, Theme};
+    /// let mut swagger = SwaggerUi::new();
+    /// swagger.theme(Theme::System);
+    ///
+    /// assert!(swagger.serve()?.contains(r#"href="./swagger-ui-dark.css""#));
+    /// # Ok::<(), Box<dyn std::error::Error + Send + Sync>>(())
+    /// ```
+    pub fn theme(&mut self, theme: Theme) -> &mut Self {
+        self.theme = theme;
+        self
+    }
+
+    /// Sets how the [`Config`] is formatted in the page, which is
+    /// [`ConfigFormat::Pretty`] by default.
+    ///
+    /// [`ConfigFormat::Minified`] makes the page smaller, which matters mostly
+    /// for [`SwaggerUi::serve_standalone_with_spec`], as the whole spec is
+    /// embedded in the config.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use swagger_ui_redist::{ConfigFormat, SwaggerUi};
+    /// let mut swagger = SwaggerUi::new();
+    /// swagger
+    ///     .config_format(ConfigFormat::Minified)
+    ///     .config()
+    ///     .urls(["/api-docs/openapi.json"]);
+    ///
+    /// assert!(
+    ///     swagger
+    ///         .serve()?
+    ///         .contains(r#""url":"/api-docs/openapi.json""#)
+    /// );
+    /// # Ok::<(), Box<dyn std::error::Error + Send + Sync>>(())
+    /// ```
+    pub fn config_format(&mut self, config_format: ConfigFormat) -> &mut Self {
+        self.config_format = config_format;
+        self
+    }
+
+    /// Sets the language of the Swagger UI page, used as the `lang` attribute
+    /// of the `<html>` element. Defaults to `en`.
+    ///
+    /// This does not translate Swagger UI itself; use
+    /// [`SwaggerUi::translation`] for that.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use swagger_ui_redist::SwaggerUi;
+    /// let mut swagger = SwaggerUi::new();
+    /// swagger.lang("de");
+    ///
+    /// assert!(swagger.serve()?.contains(r#"<html lang="de">"#));
+    /// # Ok::<(), Box<dyn std::error::Error + Send + Sync>>(())
+    /// ```
+    pub fn lang(&mut self, lang: impl Into<Cow<'static, str>>) -> &mut Self {
+        self.lang = lang.into();
+        self
+    }
+
+    /// Sets the translation of the labels displayed by Swagger UI.
+    ///
+    /// The page then includes the dictionary along with
+    /// [`SwaggerUiStaticFile::TranslationJs`], which applies it. See the
+    /// [`i18n`] module for more details.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use swagger_ui_redist::SwaggerUi;
+    /// # use swagger_ui_redist::i18n::Translation;
+    /// let mut translation = Translation::new();
+    /// translation.label("Execute", "Ausführen");
+    ///
+    /// let mut swagger = SwaggerUi::new();
+    /// swagger.lang("de").translation(translation);
+    ///
+    /// assert!(swagger.serve()?.contains(r#"src="./swagger-ui-i18n.js""#));
+    /// # Ok::<(), Box<dyn std::error::Error + Send + Sync>>(())
+    /// ```
+    pub fn translation(&mut self, translation: Translation) -> &mut Self {
+        self.translation = Some(translation);
+        self
+    }
+
+    /// Replaces the Swagger UI favicon with given image.
+    ///
+    /// The favicon is served by [`SwaggerUi::file`] as `favicon.png`,
+    /// `favicon.svg` or `favicon.ico`, depending on the image format.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use swagger_ui_redist::{Image, SwaggerUi};
+    /// let mut swagger = SwaggerUi::new();
+    /// swagger.favicon(Image::svg(br#"<svg xmlns="http://www.w3.org/2000/svg"/>"#));
+    ///
+    /// assert!(
+    ///     swagger
+    ///         .serve()?
+    ///         .contains(r#"type="image/svg+xml" href="./favicon.svg""#)
+    /// );
+    /// let favicon = swagger
+    ///     .file("/favicon.svg")?
+    ///     .expect("favicon should be present");
+    /// assert_eq!(favicon.content_type, "image/svg+xml");
+    /// # Ok::<(), Box<dyn std::error::Error + Send + Sync>>(())
+    /// ```
+    pub fn favicon(&mut self, favicon: Image) -> &mut Self {
+        self.favicon = Some(favicon);
+        self
+    }
+
+    /// Replaces the Swagger logo in the top bar with given image.
+    ///
+    /// The logo is scaled to the height of the top bar, and is served by
+    /// [`SwaggerUi::file`] as `logo.png`, `logo.svg` or `logo.ico`, depending
+    /// on the image format.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use swagger_ui_redist::{Image, SwaggerUi};
+    /// let mut swagger = SwaggerUi::new();
+    /// swagger
+    ///     .logo(Image::png(b"\x89PNG\r\n\x1a\n".as_slice()))
+    ///     .logo_link("https://example.com");
+    ///
+    /// assert!(swagger.serve()?.contains(r#"url("./logo.png")"#));
+    /// assert!(swagger.file("/logo.png")?.is_some());
+    /// # Ok::<(), Box<dyn std::error::Error + Send + Sync>>(())
+    /// ```
+    pub fn logo(&mut self, logo: Image) -> &mut Self {
+        self.logo = Some(logo);
+        self
+    }
+
+    /// Sets the URL the logo in the top bar links to.
+    pub fn logo_link(&mut self, url: impl Into<String>) -> &mut Self {
+        self.logo_link = Some(url.into());
+        self
+    }
+
+    /// Adds a custom stylesheet to the Swagger UI page.
+    ///
+    /// The stylesheet is included after the Swagger UI ones, so it can
+    /// override their rules, and is served by [`SwaggerUi::file`] under given
+    /// file name, relative to the location Swagger UI is exposed at. The
+    /// content can be provided inline, or embedded with [`include_bytes!`].
+    ///
+    /// Built-in static files take precedence over custom files with the same
+    /// name.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `file_name` contains `"`, `<` or `>`, or a `..` path segment.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use swagger_ui_redist::SwaggerUi;
+    /// let mut swagger = SwaggerUi::new();
+    /// swagger.add_stylesheet("custom.css", ".topbar { display: none; }".as_bytes());
+    ///
+    /// assert!(swagger.serve()?.contains(r#"href="./custom.css""#));
+    /// let css = swagger.file("/custom.css")?.expect("CSS should be present");
+    /// assert_eq!(css.content_type, "text/css; charset=utf-8");
+    /// # Ok::<(), Box<dyn std::error::Error + Send + Sync>>(())
+    /// ```
+    pub fn add_stylesheet(
+        &mut self,
+        file_name: impl Into<String>,
+        content: impl Into<Cow<'static, [u8]>>,
+    ) -> &mut Self {
+        self.custom_assets.push(CustomAsset::new(
+            CustomAssetKind::Stylesheet,
+            file_name.into(),
+            content.into(),
+        ));
+        self
+    }
+
+    /// Adds a custom script to the Swagger UI page.
+    ///
+    /// The script is included after the Swagger UI ones, but before Swagger UI
+    /// is initialized, and is served by [`SwaggerUi::file`] under given file
+    /// name, relative to the location Swagger UI is exposed at. The content
+    /// can be provided inline, or embedded with [`include_bytes!`].
+    ///
+    /// Built-in static files take precedence over custom files with the same
+    /// name.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `file_name` contains `"`, `<` or `>`, or a `..` path segment.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use swagger_ui_redist::SwaggerUi;
+    /// let mut swagger = SwaggerUi::new();
+    /// swagger.add_script("js/analytics.js", b"console.log('loaded');");
+    ///
+    /// assert!(swagger.serve()?.contains(r#"src="./js/analytics.js""#));
+    /// let js = swagger
+    ///     .file("/js/analytics.js")?
+    ///     .expect("JS should be present");
+    /// assert_eq!(js.content_type, "text/javascript; charset=utf-8");
+    /// # Ok::<(), Box<dyn std::error::Error + Send + Sync>>(())
+    /// ```
+    pub fn add_script(
+        &mut self,
+        file_name: impl Into<String>,
+        content: impl Into<Cow<'static, [u8]>>,
+    ) -> &mut Self {
+        self.custom_assets.push(CustomAsset::new(
+            CustomAssetKind::Script,
+            file_name.into(),
+            content.into(),
+        ));
+        self
+    }
+
+    /// Looks up the file that should be served under the given path.
+    ///
+    /// The path is relative to the location Swagger UI is exposed at. An
+    /// empty path (or `index.html`) resolves to the HTML page generated by
+    /// [`SwaggerUi::serve`], while the remaining paths are matched against
+    /// the (possibly overridden) relative paths of the static files. Leading
+    /// slashes are ignored, so both `/swagger-ui.css` and `swagger-ui.css`
+    /// resolve to the same file.
+    ///
+    /// Static files whose path has been overridden with an absolute path or a
+    /// URL are not served, as the generated page does not reference them
+    /// relative to the Swagger UI location.
+    ///
+    /// # Returns
+    ///
+    /// The file to be served, or `None` if there is no file under given path.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the Swagger UI config is invalid (see
+    /// [`InvalidConfigError`]) or fails to be serialized.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use swagger_ui_redist::SwaggerUi;
+    /// let mut swagger = SwaggerUi::new();
+    /// swagger.config().urls(["/api-docs/openapi.json"]);
+    ///
+    /// let index = swagger.file("/")?.expect("index should be present");
+    /// assert_eq!(index.content_type, "text/html; charset=utf-8");
+    ///
+    /// let css = swagger
+    ///     .file("/swagger-ui.css")?
+    ///     .expect("CSS should be present");
+    /// assert_eq!(css.content_type, "text/css; charset=utf-8");
+    ///
+    /// assert!(swagger.file("/does-not-exist.js")?.is_none());
+    /// # Ok::<(), Box<dyn std::error::Error + Send + Sync>>(())
+    /// ```
+    pub fn file(
+        &self,
+        path: &str,
+    ) -> Result<Option<SwaggerFile<'static>>, Box<dyn Error + Send + Sync>> {
+        if is_index_path(path) {
+            let bytes = self.serve()?.into_bytes();
+            return Ok(Some(SwaggerFile {
+                etag: etag(&bytes),
+                bytes: Cow::Owned(bytes),
+                content_type: INDEX_CONTENT_TYPE.to_string(),
+            }));
+        }
+
+        Ok(self.asset_file(path))
+    }
+
+    /// Returns the file at given path other than the index page, i.e. a static
+    /// file, an image or a custom asset.
+    pub(crate) fn asset_file(&self, path: &str) -> Option<SwaggerFile<'static>> {
+        if let Some(static_file) = self.file_paths.find(path) {
+            return Some(SwaggerFile {
+                bytes: Cow::Borrowed(static_file.bytes()),
+                content_type: static_file.content_type().to_string(),
+                etag: static_file.etag().to_string(),
+            });
+        }
+
+        let path = path.trim_start_matches('/');
+        for (name, image) in [("favicon", &self.favicon), ("logo", &self.logo)] {
+            if let Some(image) = image {
+                if image.file_name(name) == path {
+                    return Some(SwaggerFile {
+                        bytes: image.bytes.clone(),
+                        content_type: image.format.content_type().to_string(),
+                        etag: image.etag.clone(),
+                    });
+                }
+            }
+        }
+
+        self.custom_assets
+            .iter()
+            .find(|asset| asset.file_name == path)
+            .map(|asset| SwaggerFile {
+                bytes: asset.content.clone(),
+                content_type: asset.kind.content_type().to_string(),
+                etag: asset.etag.clone(),
+            })
     }
 
     /// Generates the HTML for the Swagger UI page.
@@ -200,7 +664,8 @@ impl SwaggerUi {
     ///
     /// # Errors
     ///
-    /// Returns an error if the Swagger UI config fails to be serialized.
+    /// Returns an error if the Swagger UI config is invalid (see
+    /// [`InvalidConfigError`]) or fails to be serialized.
     ///
     /// # Examples
     ///
@@ -213,63 +678,573 @@ impl SwaggerUi {
     /// # Ok(())
     /// # }
     /// ```
-    #[expect(clippy::missing_panics_doc)]
     pub fn serve(&self) -> Result<String, Box<dyn Error + Send + Sync>> {
-        let title = &self.title;
-        let css_path = self
-            .file_paths
-            .get(&SwaggerUiStaticFile::Css)
-            .expect("all files should be present");
-        let index_css_path = self
-            .file_paths
-            .get(&SwaggerUiStaticFile::IndexCss)
-            .expect("all files should be present");
-        let favicon_32_path = self
-            .file_paths
-            .get(&SwaggerUiStaticFile::Favicon32)
-            .expect("all files should be present");
-        let favicon_16_path = self
-            .file_paths
-            .get(&SwaggerUiStaticFile::Favicon16)
-            .expect("all files should be present");
-        let js_path = self
-            .file_paths
-            .get(&SwaggerUiStaticFile::Js)
-            .expect("all files should be present");
-        let standalone_preset_js_path = self
-            .file_paths
-            .get(&SwaggerUiStaticFile::StandalonePresetJs)
-            .expect("all files should be present");
-
-        let config = format_config(&self.config, DEFAULT_CONFIG)?;
-
-        Ok(format!(
-            r#"<!DOCTYPE html>
-<html lang="en">
-<head>
-    <meta charset="UTF-8">
-    <title>{title}</title>
-    <link rel="stylesheet" type="text/css" href="{css_path}" />
-    <link rel="stylesheet" type="text/css" href="{index_css_path}" />
-    <link rel="icon" type="image/png" href="{favicon_32_path}" sizes="32x32" />
-    <link rel="icon" type="image/png" href="{favicon_16_path}" sizes="16x16" />
-</head>
-<body>
-<div id="swagger-ui"></div>
-<script src="{js_path}" charset="UTF-8"></script>
-<script src="{standalone_preset_js_path}" charset="UTF-8"></script>
-<script>
-    window.onload = () => {{
-        {config}
-    }};
-</script>
-</body>
-</html>
-"#
-        ))
+        self.serve_with(&ConfigOverrides::new())
+    }
+
+    /// Generates the HTML page for the Swagger UI, just like
+    /// [`SwaggerUi::serve`], with some of the settings overridden.
+    ///
+    /// Neither the [`SwaggerUi`] nor its [`Config`] is modified or cloned, so
+    /// this is cheap enough to be done for every request, e.g. to show each
+    /// tenant their own specs. See [`ConfigOverrides`] for the settings which
+    /// can be overridden.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the Swagger UI config, with the overrides applied,
+    /// is invalid (see [`InvalidConfigError`]) or fails to be serialized.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use swagger_ui_redist::{ConfigOverrides, SwaggerUi};
+    /// let mut swagger = SwaggerUi::new();
+    /// swagger.config().urls(["/api-docs/openapi.json"]);
+    ///
+    /// let mut overrides = ConfigOverrides::new();
+    /// overrides
+    ///     .urls(["/acme/api-docs/openapi.json"])
+    ///     .preauthorize_api_key("api_key", "acme-key");
+    /// let html = swagger.serve_with(&overrides)?;
+    /// # Ok::<(), Box<dyn std::error::Error + Send + Sync>>(())
+    /// ```
+    pub fn serve_with(
+        &self,
+        overrides: &ConfigOverrides,
+    ) -> Result<String, Box<dyn Error + Send + Sync>> {
+        let css_path = self.file_paths.get(SwaggerUiStaticFile::Css);
+        let index_css_path = self.file_paths.get(SwaggerUiStaticFile::IndexCss);
+        let js_path = self.file_paths.get(SwaggerUiStaticFile::Js);
+        let standalone_preset_js_path =
+            self.file_paths.get(SwaggerUiStaticFile::StandalonePresetJs);
+
+        let assets = IndexAssets {
+            css: linked_stylesheet(css_path),
+            index_css: linked_stylesheet(index_css_path),
+            favicon_32: self.favicon_url(SwaggerUiStaticFile::Favicon32, false),
+            favicon_16: self.favicon_url(SwaggerUiStaticFile::Favicon16, false),
+            favicon_type: self.favicon_type(),
+            js: linked_script(js_path),
+            standalone_preset_js: linked_script(standalone_preset_js_path),
+            custom_css: self.optional_assets(CustomAssetKind::Stylesheet, false),
+            custom_js: self.optional_assets(CustomAssetKind::Script, false),
+        };
+        let config = format_config(&self.config, overrides, self.config_format, DEFAULT_CONFIG)?;
+        let title = overrides.title.as_deref().unwrap_or(&self.title);
+
+        Ok(self.render_page(&assets, title, &config))
+    }
+
+    /// Generates a single-file HTML document for the Swagger UI page.
+    ///
+    /// This works just like [`SwaggerUi::serve`], but instead of referencing
+    /// the static files, all of them (the stylesheets, the JavaScript files
+    /// and the favicons) are inlined into the document. The resulting file
+    /// does not need any other files to be served along with it, so it's
+    /// useful for handing out API documentation as a single file.
+    ///
+    /// Note that the OpenAPI specs are still fetched from the URLs set in the
+    /// [`Config`]. If the document should be viewable without any server
+    /// (e.g. when opened from a `file://` URL), use
+    /// [`SwaggerUi::serve_standalone_with_spec`] instead.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the Swagger UI config is invalid (see
+    /// [`InvalidConfigError`]) or fails to be serialized.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use swagger_ui_redist::SwaggerUi;
+    /// let mut swagger = SwaggerUi::new();
+    /// swagger
+    ///     .config()
+    ///     .urls(["https://example.com/api-docs/openapi.json"]);
+    /// let html = swagger.serve_standalone()?;
+    /// # Ok::<(), Box<dyn std::error::Error + Send + Sync>>(())
+    /// ```
+    pub fn serve_standalone(&self) -> Result<String, Box<dyn Error + Send + Sync>> {
+        let config = format_config(
+            &self.config,
+            &ConfigOverrides::new(),
+            self.config_format,
+            DEFAULT_CONFIG,
+        )?;
+
+        Ok(self.render_page(&self.inlined_assets(), &self.title, &config))
+    }
+
+    /// Generates a single-file HTML document for the Swagger UI page with the
+    /// OpenAPI spec embedded.
+    ///
+    /// This works just like [`SwaggerUi::serve_standalone`], but additionally
+    /// embeds given OpenAPI spec (in JSON format) into the document. The spec
+    /// URLs set in the [`Config`] are ignored. This makes it possible to
+    /// open the resulting file directly in the browser, without any server.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the spec is not valid JSON or if the Swagger UI
+    /// config fails to be serialized. When Swagger UI 4 is bundled (see the
+    /// `v4` feature), an [`IncompatibleConfigError`] is returned for OpenAPI
+    /// 3.1 specs, which only Swagger UI 5 can render.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use swagger_ui_redist::SwaggerUi;
+    /// let swagger = SwaggerUi::new();
+    /// let spec =
+    ///     r#"{"openapi": "3.0.3", "info": {"title": "My API", "version": "1.0.0"}, "paths": {}}"#;
+    /// let html = swagger.serve_standalone_with_spec(spec)?;
+    /// # Ok::<(), Box<dyn std::error::Error + Send + Sync>>(())
+    /// ```
+    pub fn serve_standalone_with_spec(
+        &self,
+        spec: &str,
+    ) -> Result<String, Box<dyn Error + Send + Sync>> {
+        let overrides = ConfigOverrides {
+            specs: Some(Specs::embedded(serde_json::from_str(spec)?)),
+            ..ConfigOverrides::default()
+        };
+        let config = format_config(&self.config, &overrides, self.config_format, DEFAULT_CONFIG)?;
+
+        Ok(self.render_page(&self.inlined_assets(), &self.title, &config))
+    }
+
+    fn inlined_assets(&self) -> IndexAssets<'_> {
+        IndexAssets {
+            css: inline_stylesheet(SwaggerUiStaticFile::Css.bytes()),
+            index_css: inline_stylesheet(SwaggerUiStaticFile::IndexCss.bytes()),
+            favicon_32: self.favicon_url(SwaggerUiStaticFile::Favicon32, true),
+            favicon_16: self.favicon_url(SwaggerUiStaticFile::Favicon16, true),
+            favicon_type: self.favicon_type(),
+            js: inline_script(SwaggerUiStaticFile::Js.bytes()),
+            standalone_preset_js: inline_script(SwaggerUiStaticFile::StandalonePresetJs.bytes()),
+            custom_css: self.optional_assets(CustomAssetKind::Stylesheet, true),
+            custom_js: self.optional_assets(CustomAssetKind::Script, true),
+        }
+    }
+
+    /// Returns the URL of the favicon, which is either the custom favicon or
+    /// given built-in one.
+    fn favicon_url(&self, static_file: SwaggerUiStaticFile, inline: bool) -> Cow<'_, str> {
+        match (&self.favicon, inline) {
+            (Some(favicon), false) => Cow::Owned(format!("./{}", favicon.file_name("favicon"))),
+            (Some(favicon), true) => Cow::Owned(favicon.data_url()),
+            (None, false) => Cow::Borrowed(self.file_paths.get(static_file)),
+            (None, true) => Cow::Owned(data_url(static_file)),
+        }
+    }
+
+    fn favicon_type(&self) -> &'static str {
+        self.favicon
+            .as_ref()
+            .map_or("image/png", |favicon| favicon.format.content_type())
+    }
+
+    /// Renders the tags including the optional assets of given kind, one per
+    /// line: the dark theme if enabled, the branding, and the custom assets.
+    fn optional_assets(&self, kind: CustomAssetKind, inline: bool) -> String {
+        let mut tags = Vec::new();
+        let dark_theme = self.theme != Theme::Light;
+
+        match kind {
+            CustomAssetKind::Stylesheet => {
+                if dark_theme {
+                    tags.push(if inline {
+                        inline_stylesheet(SwaggerUiStaticFile::DarkThemeCss.bytes())
+                    } else {
+                        linked_stylesheet(self.file_paths.get(SwaggerUiStaticFile::DarkThemeCss))
+                    });
+                }
+                if let Some(logo) = &self.logo {
+                    tags.push(logo_stylesheet(&if inline {
+                        logo.data_url()
+                    } else {
+                        format!("./{}", logo.file_name("logo"))
+                    }));
+                }
+            }
+            CustomAssetKind::Script => {
+                if dark_theme {
+                    let attributes = self.theme_script_attributes();
+                    tags.push(if inline {
+                        inline_script_with_attributes(
+                            SwaggerUiStaticFile::DarkThemeJs.bytes(),
+                            &attributes,
+                        )
+                    } else {
+                        format!(
+                            r#"<script src="{}" {attributes}></script>"#,
+                            self.file_paths.get(SwaggerUiStaticFile::DarkThemeJs)
+                        )
+                    });
+                }
+                if let Some(translation) = &self.translation {
+                    tags.push(format!(
+                        r#"<script type="application/json" id="swagger-ui-translation">{}</script>"#,
+                        translation.to_script_json()
+                    ));
+                    tags.push(if inline {
+                        inline_script(SwaggerUiStaticFile::TranslationJs.bytes())
+                    } else {
+                        linked_script(self.file_paths.get(SwaggerUiStaticFile::TranslationJs))
+                    });
+                }
+                if let Some(logo_link) = &self.logo_link {
+                    tags.push(logo_link_script(logo_link));
+                }
+            }
+        }
+
+        for asset in self.custom_assets.iter().filter(|asset| asset.kind == kind) {
+            let path = format!("./{}", asset.file_name);
+            tags.push(match (kind, inline) {
+                (CustomAssetKind::Stylesheet, false) => linked_stylesheet(&path),
+                (CustomAssetKind::Stylesheet, true) => inline_stylesheet(&asset.content),
+                (CustomAssetKind::Script, false) => linked_script(&path),
+                (CustomAssetKind::Script, true) => inline_script(&asset.content),
+            });
+        }
+
+        let indent = match kind {
+            CustomAssetKind::Stylesheet => "    ",
+            CustomAssetKind::Script => "",
+        };
+        tags.into_iter().fold(String::new(), |mut tags, tag| {
+            tags.push_str(indent);
+            tags.push_str(&tag);
+            tags.push('\n');
+            tags
+        })
+    }
+
+    fn theme_script_attributes(&self) -> String {
+        let default_theme = match self.theme {
+            Theme::Dark => "dark",
+            Theme::Light | Theme::System => "system",
+        };
+
+        format!(r#"data-default-theme="{default_theme}" charset="UTF-8""#)
+    }
+
+    fn render_page(&self, assets: &IndexAssets<'_>, title: &str, config: &str) -> String {
+        let lang = escape_html(&self.lang);
+        let title = escape_html(title);
+
+        self.template.render(|slot| match slot {
+            "lang" => Some(&lang),
+            "title" => Some(&title),
+            "css" => Some(&assets.css),
+            "index_css" => Some(&assets.index_css),
+            "favicon_32" => Some(&assets.favicon_32),
+            "favicon_16" => Some(&assets.favicon_16),
+            "favicon_type" => Some(assets.favicon_type),
+            "js" => Some(&assets.js),
+            "standalone_preset_js" => Some(&assets.standalone_preset_js),
+            "custom_css" => Some(&assets.custom_css),
+            "custom_js" => Some(&assets.custom_js),
+            "config" => Some(config),
+            _ => None,
+        })
     }
 }
 
+/// The tags (or URLs, in case of favicons) used to include the static files in
+/// the generated HTML page.
+#[derive(Debug)]
+struct IndexAssets<'a> {
+    css: String,
+    index_css: String,
+    favicon_32: Cow<'a, str>,
+    favicon_16: Cow<'a, str>,
+    favicon_type: &'static str,
+    js: String,
+    standalone_preset_js: String,
+    custom_css: String,
+    custom_js: String,
+}
+
+/// How the [`Config`] is formatted in the page, set with
+/// [`SwaggerUi::config_format`].
+#[non_exhaustive]
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq)]
+pub enum ConfigFormat {
+    /// Indented, one option per line, which is easy to read when debugging.
+    #[default]
+    Pretty,
+    /// Without any whitespace, which makes the page smaller.
+    Minified,
+}
+
+impl ConfigFormat {
+    fn to_json<T: Serialize + ?Sized>(self, value: &T) -> serde_json::Result<String> {
+        match self {
+            ConfigFormat::Pretty => serde_json::to_string_pretty(value),
+            ConfigFormat::Minified => serde_json::to_string(value),
+        }
+    }
+}
+
+/// Color theme of the Swagger UI page, set with [`SwaggerUi::theme`].
+#[non_exhaustive]
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq)]
+pub enum Theme {
+    /// The light theme Swagger UI comes with. The dark theme is not available.
+    #[default]
+    Light,
+    /// Follows the color scheme preferred by the user's system (the
+    /// `prefers-color-scheme` media query), until the user picks a theme with
+    /// the button in the top bar.
+    System,
+    /// The dark theme, until the user picks a theme with the button in the
+    /// top bar.
+    Dark,
+}
+
+/// Image used to brand the Swagger UI page, see [`SwaggerUi::favicon`] and
+/// [`SwaggerUi::logo`].
+///
+/// # Examples
+///
+/// ```
+/// # use swagger_ui_redist::Image;
+/// let logo = Image::svg(br#"<svg xmlns="http://www.w3.org/2000/svg"/>"#);
+/// let favicon = Image::png(vec![0x89, b'P', b'N', b'G']);
+/// ```
+#[non_exhaustive]
+#[derive(Debug, Clone)]
+pub struct Image {
+    bytes: Cow<'static, [u8]>,
+    format: ImageFormat,
+    etag: String,
+}
+
+impl Image {
+    /// Creates a new PNG [`Image`].
+    #[must_use]
+    pub fn png(bytes: impl Into<Cow<'static, [u8]>>) -> Self {
+        Self::new(bytes.into(), ImageFormat::Png)
+    }
+
+    /// Creates a new SVG [`Image`].
+    #[must_use]
+    pub fn svg(bytes: impl Into<Cow<'static, [u8]>>) -> Self {
+        Self::new(bytes.into(), ImageFormat::Svg)
+    }
+
+    /// Creates a new ICO [`Image`].
+    #[must_use]
+    pub fn ico(bytes: impl Into<Cow<'static, [u8]>>) -> Self {
+        Self::new(bytes.into(), ImageFormat::Ico)
+    }
+
+    fn new(bytes: Cow<'static, [u8]>, format: ImageFormat) -> Self {
+        Self {
+            etag: etag(&bytes),
+            bytes,
+            format,
+        }
+    }
+
+    fn file_name(&self, name: &str) -> String {
+        format!("{name}.{}", self.format.extension())
+    }
+
+    fn data_url(&self) -> String {
+        format!(
+            "data:{};base64,{}",
+            self.format.content_type(),
+            base64_encode(&self.bytes)
+        )
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+enum ImageFormat {
+    Png,
+    Svg,
+    Ico,
+}
+
+impl ImageFormat {
+    fn content_type(self) -> &'static str {
+        match self {
+            ImageFormat::Png => "image/png",
+            ImageFormat::Svg => "image/svg+xml",
+            ImageFormat::Ico => "image/x-icon",
+        }
+    }
+
+    fn extension(self) -> &'static str {
+        match self {
+            ImageFormat::Png => "png",
+            ImageFormat::Svg => "svg",
+            ImageFormat::Ico => "ico",
+        }
+    }
+}
+
+/// A custom stylesheet or script added with [`SwaggerUi::add_stylesheet`] or
+/// [`SwaggerUi::add_script`].
+#[derive(Debug, Clone)]
+struct CustomAsset {
+    kind: CustomAssetKind,
+    file_name: String,
+    content: Cow<'static, [u8]>,
+    etag: String,
+}
+
+impl CustomAsset {
+    fn new(kind: CustomAssetKind, file_name: String, content: Cow<'static, [u8]>) -> Self {
+        assert!(
+            !file_name.contains(['"', '<', '>'])
+                && !file_name.split('/').any(|segment| segment == ".."),
+            "invalid custom asset file name: {file_name:?}"
+        );
+
+        Self {
+            kind,
+            file_name: match file_name.strip_prefix("./") {
+                Some(file_name) => file_name.to_string(),
+                None => file_name,
+            },
+            etag: etag(&content),
+            content,
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+enum CustomAssetKind {
+    Stylesheet,
+    Script,
+}
+
+impl CustomAssetKind {
+    fn content_type(self) -> &'static str {
+        match self {
+            CustomAssetKind::Stylesheet => "text/css; charset=utf-8",
+            CustomAssetKind::Script => "text/javascript; charset=utf-8",
+        }
+    }
+}
+
+fn linked_stylesheet(path: &str) -> String {
+    let path = escape_html(path);
+    format!(r#"<link rel="stylesheet" type="text/css" href="{path}" />"#)
+}
+
+fn linked_script(path: &str) -> String {
+    let path = escape_html(path);
+    format!(r#"<script src="{path}" charset="UTF-8"></script>"#)
+}
+
+fn inline_stylesheet(bytes: &[u8]) -> String {
+    let css = String::from_utf8_lossy(bytes);
+
+    format!("<style>\n{}\n</style>", escape_end_tags(&css, "style"))
+}
+
+fn inline_script(bytes: &[u8]) -> String {
+    inline_script_with_attributes(bytes, r#"charset="UTF-8""#)
+}
+
+fn inline_script_with_attributes(bytes: &[u8], attributes: &str) -> String {
+    let js = String::from_utf8_lossy(bytes);
+
+    format!(
+        "<script {attributes}>\n{}\n</script>",
+        escape_end_tags(&js, "script")
+    )
+}
+
+/// Escapes the end tags of given element in the contents of an inline
+/// `<style>` or `<script>` element, so that they don't close it early. As in
+/// HTML, the tag name is matched case-insensitively.
+fn escape_end_tags(text: &str, tag: &str) -> String {
+    let end_tag = format!("</{tag}");
+    // ASCII lowercasing keeps the byte offsets intact
+    let lowercase = text.to_ascii_lowercase();
+
+    let mut escaped = String::with_capacity(text.len());
+    let mut last = 0;
+    for (index, _) in lowercase.match_indices(&end_tag) {
+        escaped.push_str(&text[last..index]);
+        escaped.push_str(r"<\/");
+        last = index + "</".len();
+    }
+    escaped.push_str(&text[last..]);
+    escaped
+}
+
+/// Replaces the Swagger logo in the top bar with the image at given URL. The
+/// original logo is only hidden, as the top bar is managed by Swagger UI.
+fn logo_stylesheet(url: &str) -> String {
+    format!(
+        r#"<style>
+.swagger-ui .topbar-wrapper > a:first-child {{ background: url("{url}") left center / contain no-repeat; }}
+.swagger-ui .topbar-wrapper > a:first-child > * {{ visibility: hidden; }}
+</style>"#
+    )
+}
+
+/// Makes the logo in the top bar link to given URL, once the top bar is
+/// rendered.
+fn logo_link_script(url: &str) -> String {
+    let url = serde_json::Value::from(url)
+        .to_string()
+        .replace("</", r"<\/");
+
+    format!(
+        r"<script>
+new MutationObserver(function (mutations, observer) {{
+    var link = document.querySelector('.swagger-ui .topbar-wrapper > a');
+    if (link) {{
+        link.href = {url};
+        observer.disconnect();
+    }}
+}}).observe(document.documentElement, {{ childList: true, subtree: true }});
+</script>"
+    )
+}
+
+fn data_url(static_file: SwaggerUiStaticFile) -> String {
+    let content_type = static_file.content_type();
+    let data = base64_encode(static_file.bytes());
+
+    format!("data:{content_type};base64,{data}")
+}
+
+/// Encodes given bytes with the standard base64 alphabet, with padding.
+fn base64_encode(bytes: &[u8]) -> String {
+    const ALPHABET: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+    let mut encoded = String::with_capacity(bytes.len().div_ceil(3) * 4);
+    for chunk in bytes.chunks(3) {
+        let buffer = match *chunk {
+            [a] => u32::from(a) << 16,
+            [a, b] => u32::from(a) << 16 | u32::from(b) << 8,
+            [a, b, c] => u32::from(a) << 16 | u32::from(b) << 8 | u32::from(c),
+            _ => unreachable!("chunks should have between 1 and 3 bytes"),
+        };
+
+        for index in 0..4 {
+            if index <= chunk.len() {
+                let sextet = (buffer >> (18 - 6 * index)) & 0x3f;
+                encoded.push(char::from(ALPHABET[sextet as usize]));
+            } else {
+                encoded.push('=');
+            }
+        }
+    }
+
+    encoded
+}
+
 /// Represents the static files required by Swagger UI.
 ///
 /// This enum is used to identify and manage the various static assets needed
@@ -289,6 +1264,20 @@ pub enum SwaggerUiStaticFile {
     Favicon16,
     /// The 32x32 favicon.
     Favicon32,
+    /// The page Swagger UI redirects to after completing the OAuth2
+    /// authorization flow. Use [`Config::oauth2_redirect_url`] to point
+    /// Swagger UI to it.
+    OAuth2Redirect,
+    /// The license of Swagger UI, the same as [`SwaggerUi::LICENSE`].
+    License,
+    /// The stylesheet of the dark theme, see [`SwaggerUi::theme`].
+    DarkThemeCss,
+    /// The script switching between the light and the dark theme, see
+    /// [`SwaggerUi::theme`].
+    DarkThemeJs,
+    /// The script translating the labels of Swagger UI, see
+    /// [`SwaggerUi::translation`].
+    TranslationJs,
 }
 
 impl SwaggerUiStaticFile {
@@ -304,28 +1293,17 @@ impl SwaggerUiStaticFile {
     pub fn all() -> &'static [Self] {
         &[
             SwaggerUiStaticFile::Css,
-            SwaggerUiStaticFile::IndexCss,
-            SwaggerUiStaticFile::Js,
-            SwaggerUiStaticFile::StandalonePresetJs,
-            SwaggerUiStaticFile::Favicon16,
-            SwaggerUiStaticFile::Favicon32,
-        ]
-    }
-
-    #[must_use]
-    fn default_map() -> HashMap<Self, String> {
-        let mut map = HashMap::new();
-
-        for file in Self::all() {
-            map.insert(*file, file.default_path());
-        }
-
-        map
-    }
-
-    #[must_use]
-    fn default_path(self) -> String {
-        format!("./{}", self.file_name())
+            SwaggerUiStaticFile::IndexCss,
+            SwaggerUiStaticFile::Js,
+            SwaggerUiStaticFile::StandalonePresetJs,
+            SwaggerUiStaticFile::Favicon16,
+            SwaggerUiStaticFile::Favicon32,
+            SwaggerUiStaticFile::OAuth2Redirect,
+            SwaggerUiStaticFile::License,
+            SwaggerUiStaticFile::DarkThemeCss,
+            SwaggerUiStaticFile::DarkThemeJs,
+            SwaggerUiStaticFile::TranslationJs,
+        ]
     }
 
     /// Returns the filename for a specific static file.
@@ -338,22 +1316,145 @@ impl SwaggerUiStaticFile {
             SwaggerUiStaticFile::StandalonePresetJs => "swagger-ui-standalone-preset.js",
             SwaggerUiStaticFile::Favicon16 => "favicon-16x16.png",
             SwaggerUiStaticFile::Favicon32 => "favicon-32x32.png",
+            SwaggerUiStaticFile::OAuth2Redirect => "oauth2-redirect.html",
+            SwaggerUiStaticFile::License => "LICENSE",
+            SwaggerUiStaticFile::DarkThemeCss => "swagger-ui-dark.css",
+            SwaggerUiStaticFile::DarkThemeJs => "swagger-ui-dark.js",
+            SwaggerUiStaticFile::TranslationJs => "swagger-ui-i18n.js",
+        }
+    }
+
+    /// Returns the static file with given filename, if there is one.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use swagger_ui_redist::SwaggerUiStaticFile;
+    /// assert_eq!(
+    ///     SwaggerUiStaticFile::from_file_name("swagger-ui.css"),
+    ///     Some(SwaggerUiStaticFile::Css)
+    /// );
+    /// assert_eq!(SwaggerUiStaticFile::from_file_name("index.html"), None);
+    /// ```
+    #[must_use]
+    pub fn from_file_name(file_name: &str) -> Option<Self> {
+        Self::all()
+            .iter()
+            .copied()
+            .find(|file| file.file_name() == file_name)
+    }
+
+    /// Returns the content type of a specific static file, suitable for use in
+    /// the `Content-Type` header.
+    #[must_use]
+    pub fn content_type(&self) -> &'static str {
+        match self {
+            SwaggerUiStaticFile::Css
+            | SwaggerUiStaticFile::IndexCss
+            | SwaggerUiStaticFile::DarkThemeCss => "text/css; charset=utf-8",
+            SwaggerUiStaticFile::Js
+            | SwaggerUiStaticFile::StandalonePresetJs
+            | SwaggerUiStaticFile::DarkThemeJs
+            | SwaggerUiStaticFile::TranslationJs => "text/javascript; charset=utf-8",
+            SwaggerUiStaticFile::Favicon16 | SwaggerUiStaticFile::Favicon32 => "image/png",
+            SwaggerUiStaticFile::OAuth2Redirect => INDEX_CONTENT_TYPE,
+            SwaggerUiStaticFile::License => "text/plain; charset=utf-8",
         }
     }
+
+    /// Returns the raw content of a specific static file.
+    ///
+    /// This is the same content as returned by [`SwaggerUi::static_files`].
+    #[must_use]
+    #[expect(clippy::missing_panics_doc)]
+    pub fn bytes(&self) -> &'static [u8] {
+        SwaggerUi::static_files()
+            .iter()
+            .find(|(file, _)| file == self)
+            .map(|(_, bytes)| *bytes)
+            .expect("all files should be present")
+    }
+
+    /// Returns the entity tag of a specific static file, suitable for use in
+    /// the `ETag` header.
+    ///
+    /// The entity tag is derived from the content of the file, so it only
+    /// changes when the file itself changes.
+    #[must_use]
+    #[expect(clippy::missing_panics_doc)]
+    pub fn etag(&self) -> &'static str {
+        static ETAGS: OnceLock<HashMap<SwaggerUiStaticFile, String>> = OnceLock::new();
+
+        ETAGS
+            .get_or_init(|| {
+                SwaggerUi::static_files()
+                    .iter()
+                    .map(|(file, bytes)| (*file, etag(bytes)))
+                    .collect()
+            })
+            .get(self)
+            .expect("all files should be present")
+    }
+}
+
+impl StaticFile for SwaggerUiStaticFile {
+    fn all() -> &'static [Self] {
+        Self::all()
+    }
+
+    fn file_name(&self) -> &'static str {
+        self.file_name()
+    }
+
+    fn content_type(&self) -> &'static str {
+        self.content_type()
+    }
+
+    fn bytes(&self) -> &'static [u8] {
+        self.bytes()
+    }
+}
+
+impl OpenApiGui for SwaggerUi {
+    type StaticFile = SwaggerUiStaticFile;
+
+    fn set_title(&mut self, title: Cow<'static, str>) {
+        self.title = title;
+    }
+
+    fn set_spec_urls(&mut self, urls: Vec<SpecUrl>) {
+        self.config.urls(urls);
+    }
+
+    fn file_paths(&self) -> &FilePaths<SwaggerUiStaticFile> {
+        &self.file_paths
+    }
+
+    fn file_paths_mut(&mut self) -> &mut FilePaths<SwaggerUiStaticFile> {
+        &mut self.file_paths
+    }
+
+    fn render_index(&self) -> Result<String, Box<dyn Error + Send + Sync>> {
+        self.serve()
+    }
+
+    fn file(&self, path: &str) -> Result<Option<GuiFile<'static>>, Box<dyn Error + Send + Sync>> {
+        Ok(SwaggerUi::file(self, path)?.map(|file| GuiFile::new(file.bytes, file.content_type)))
+    }
 }
 
 /// Rust type for Swagger UI url configuration object.
 #[non_exhaustive]
 #[derive(Debug, Default, Serialize, Clone)]
-pub struct Url<'a> {
-    name: Cow<'a, str>,
+pub struct Url {
+    name: String,
     #[allow(clippy::struct_field_names)]
-    url: Cow<'a, str>,
+    url: String,
     #[serde(skip)]
     primary: bool,
 }
 
-impl<'a> Url<'a> {
+impl Url {
     /// Create new [`Url`].
     ///
     /// Name is shown in the select dropdown when there are multiple docs in
@@ -361,17 +1462,23 @@ impl<'a> Url<'a> {
     ///
     /// Url is path which exposes the OpenAPI doc.
     ///
+    /// Both borrowed and owned strings are accepted, so the URLs can be built
+    /// dynamically as well.
+    ///
     /// # Examples
     ///
     /// ```
     /// # use swagger_ui_redist::Url;
     /// let url = Url::new("My Api", "/api-docs/openapi.json");
+    ///
+    /// let tenant = String::from("acme");
+    /// let url = Url::new(format!("{tenant} API"), format!("/{tenant}/openapi.json"));
     /// ```
     #[must_use]
-    pub fn new(name: &'a str, url: &'a str) -> Self {
+    pub fn new(name: impl Into<String>, url: impl Into<String>) -> Self {
         Self {
-            name: Cow::Borrowed(name),
-            url: Cow::Borrowed(url),
+            name: name.into(),
+            url: url.into(),
             ..Default::default()
         }
     }
@@ -396,38 +1503,51 @@ impl<'a> Url<'a> {
     /// let url = Url::with_primary("My Api", "/api-docs/openapi.json", true);
     /// ```
     #[must_use]
-    pub fn with_primary(name: &'a str, url: &'a str, primary: bool) -> Self {
-        Self {
-            name: Cow::Borrowed(name),
-            url: Cow::Borrowed(url),
-            primary,
-        }
+    pub fn with_primary(name: impl Into<String>, url: impl Into<String>, primary: bool) -> Self {
+        Self::new(name, url).primary(primary)
+    }
+
+    /// Sets the primary flag of the [`Url`].
+    ///
+    /// See [`Url::with_primary`] for more details.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use swagger_ui_redist::Url;
+    /// let url = Url::new("My Api", "/api-docs/openapi.json").primary(true);
+    /// ```
+    #[must_use]
+    pub fn primary(mut self, primary: bool) -> Self {
+        self.primary = primary;
+        self
     }
 }
 
-impl<'a> From<&'a str> for Url<'a> {
-    fn from(url: &'a str) -> Self {
-        Self {
-            url: Cow::Borrowed(url),
-            ..Default::default()
-        }
+impl From<&str> for Url {
+    fn from(url: &str) -> Self {
+        Self::new(String::new(), url)
     }
 }
 
-impl From<String> for Url<'_> {
+impl From<String> for Url {
     fn from(url: String) -> Self {
-        Self {
-            url: Cow::Owned(url),
-            ..Default::default()
-        }
+        Self::new(String::new(), url)
+    }
+}
+
+impl From<Cow<'_, str>> for Url {
+    fn from(url: Cow<'_, str>) -> Self {
+        Self::new(String::new(), url)
     }
 }
 
-impl From<Cow<'static, str>> for Url<'_> {
-    fn from(url: Cow<'static, str>) -> Self {
+impl From<SpecUrl> for Url {
+    fn from(url: SpecUrl) -> Self {
         Self {
-            url,
-            ..Default::default()
+            name: url.name.unwrap_or_default(),
+            url: url.url,
+            primary: url.primary,
         }
     }
 }
@@ -451,19 +1571,34 @@ const SWAGGER_BASE_LAYOUT: &str = "BaseLayout";
 /// config.urls(["/api-docs/openapi.json"]);
 /// ```
 #[non_exhaustive]
-#[derive(Debug, Serialize, Clone)]
-#[serde(rename_all = "camelCase")]
-pub struct Config<'a> {
+#[derive(Debug, Clone)]
+pub struct Config {
     /// Url to fetch external configuration from.
-    #[serde(skip_serializing_if = "Option::is_none")]
     #[allow(clippy::struct_field_names)]
     config_url: Option<String>,
 
     /// Id of the DOM element where `Swagger UI` will put it's user interface.
-    #[serde(skip_serializing_if = "Option::is_none")]
-    #[serde(rename = "dom_id")]
     dom_id: Option<String>,
 
+    /// The OpenAPI specs displayed by Swagger UI.
+    specs: Specs,
+
+    /// The options passed to Swagger UI as they are.
+    options: Options,
+
+    /// [`oauth::Config`] the Swagger UI is using for auth flow.
+    oauth: Option<oauth::Config>,
+
+    /// API keys and credentials the security schemes are authorized with
+    /// once the spec is loaded.
+    preauthorizations: Vec<Preauthorization>,
+}
+
+/// The source of the OpenAPI specs displayed by Swagger UI, either URLs or an
+/// embedded spec.
+#[derive(Debug, Default, Serialize, Clone)]
+#[serde(rename_all = "camelCase")]
+struct Specs {
     /// [`Url`] the Swagger UI is serving.
     #[serde(skip_serializing_if = "Option::is_none")]
     url: Option<String>,
@@ -474,8 +1609,85 @@ pub struct Config<'a> {
 
     /// [`Url`]s the Swagger UI is serving.
     #[serde(skip_serializing_if = "Vec::is_empty")]
-    urls: Vec<Url<'a>>,
+    urls: Vec<Url>,
+
+    /// OpenAPI spec embedded in the page, used instead of the [`Url`]s.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    spec: Option<serde_json::Value>,
+}
+
+impl Specs {
+    /// Creates the specs from given URLs, see [`Config::urls`].
+    fn from_urls<I: IntoIterator<Item = U>, U: Into<Url>>(urls: I) -> Self {
+        let mut urls = urls.into_iter().map(Into::into).collect::<Vec<Url>>();
+
+        if urls.len() == 1 && urls[0].name.is_empty() {
+            return Self {
+                url: urls.pop().map(|url| url.url),
+                ..Self::default()
+            };
+        }
+
+        for url in &mut urls {
+            if url.name.is_empty() {
+                url.name.clone_from(&url.url);
+            }
+        }
+        Self {
+            urls_primary_name: urls
+                .iter()
+                .find(|url| url.primary)
+                .map(|url| url.name.clone()),
+            urls,
+            ..Self::default()
+        }
+    }
+
+    /// Creates the specs embedding given OpenAPI spec.
+    fn embedded(spec: serde_json::Value) -> Self {
+        Self {
+            spec: Some(spec),
+            ..Self::default()
+        }
+    }
+}
+
+/// The [`Config`] as passed to Swagger UI, borrowing the specs separately so
+/// they can be overridden without cloning the config.
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct ConfigJson<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    config_url: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "dom_id")]
+    dom_id: Option<&'a str>,
+    #[serde(flatten)]
+    specs: &'a Specs,
+    #[serde(flatten)]
+    options: &'a Options,
+}
+
+impl<'a> ConfigJson<'a> {
+    fn new(config: &'a Config, specs: &'a Specs) -> Self {
+        Self {
+            config_url: config.config_url.as_deref(),
+            dom_id: config.dom_id.as_deref(),
+            specs,
+            options: &config.options,
+        }
+    }
+}
 
+impl Serialize for Config {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        ConfigJson::new(self, &self.specs).serialize(serializer)
+    }
+}
+
+/// The [`Config`] options which are passed to Swagger UI as they are.
+#[derive(Debug, Serialize, Clone)]
+#[serde(rename_all = "camelCase")]
+struct Options {
     /// Enables overriding configuration parameters with url query parameters.
     #[serde(skip_serializing_if = "Option::is_none")]
     query_config_enabled: Option<bool>,
@@ -568,16 +1780,12 @@ pub struct Config<'a> {
     #[serde(skip_serializing_if = "Option::is_none")]
     persist_authorization: Option<bool>,
 
-    /// [`oauth::Config`] the Swagger UI is using for auth flow.
-    #[serde(skip)]
-    oauth: Option<oauth::Config>,
-
     /// Defines syntax highlighting specific options.
     #[serde(skip_serializing_if = "Option::is_none")]
     syntax_highlight: Option<SyntaxHighlight>,
 
     /// The layout of Swagger UI uses, default is `"StandaloneLayout"`.
-    layout: &'a str,
+    layout: &'static str,
 
     /// Basic authentication configuration. If configured, the Swagger UI will
     /// prompt for basic auth credentials.
@@ -585,7 +1793,7 @@ pub struct Config<'a> {
     basic_auth: Option<BasicAuth>,
 }
 
-impl<'a> Config<'a> {
+impl Config {
     /// Constructs a new [`Config`] with default settings.
     ///
     /// # Examples
@@ -600,18 +1808,26 @@ impl<'a> Config<'a> {
     }
 
     /// Sets the URLs for the OpenAPI specifications to be displayed in Swagger
-    /// UI.
+    /// UI, replacing the previously set ones.
     ///
     /// This method accepts an iterator of items that can be converted into
-    /// [`Url`] objects. It handles both single and multiple URL scenarios
-    /// appropriately:
-    /// - For a single URL, it will be set as the primary URL
-    /// - For multiple URLs, they will be displayed in a dropdown selector
+    /// [`Url`] objects:
+    /// - A single URL without a name is displayed on its own, without the
+    ///   dropdown selector (its primary flag has no effect, as it's the only
+    ///   one).
+    /// - Otherwise, the URLs are displayed in a dropdown selector. The URLs
+    ///   without a name are listed under the URL itself. The URL marked as
+    ///   primary (see [`Url::primary`]) is selected initially, or the first one
+    ///   if there is none.
+    ///
+    /// At most one URL can be marked as primary. Otherwise,
+    /// [`SwaggerUi::serve`] returns an [`InvalidConfigError`]; use
+    /// [`Config::try_urls`] to get the error when setting the URLs instead.
     ///
     /// # Examples
     ///
     /// ```
-    /// # use swagger_ui_redist::Config;
+    /// # use swagger_ui_redist::{Config, Url};
     /// let mut config = Config::new();
     ///
     /// // Single URL
@@ -619,60 +1835,60 @@ impl<'a> Config<'a> {
     ///
     /// // Multiple URLs
     /// config.urls(["/api-docs/openapi.json", "/api-docs/openapi-v2.json"]);
+    ///
+    /// // Multiple URLs, with the second one selected initially
+    /// config.urls([
+    ///     Url::new("v1", "/api-docs/openapi.json"),
+    ///     Url::new("v2", "/api-docs/openapi-v2.json").primary(true),
+    /// ]);
     /// ```
-    pub fn urls<I: IntoIterator<Item = U>, U: Into<Url<'a>>>(&mut self, urls: I) -> &mut Self {
-        let urls = urls.into_iter().map(Into::into).collect::<Vec<Url<'a>>>();
-        let urls_len = urls.len();
-
-        if urls_len == 1 {
-            self.single_url(urls);
-        } else {
-            self.multiple_urls(urls);
-        }
-
+    pub fn urls<I: IntoIterator<Item = U>, U: Into<Url>>(&mut self, urls: I) -> &mut Self {
+        self.specs = Specs::from_urls(urls);
         self
     }
 
-    fn multiple_urls(&mut self, urls: Vec<Url<'a>>) {
-        let primary_name = urls
-            .iter()
-            .find(|url| url.primary)
-            .map(|url| url.name.to_string());
-
-        self.urls_primary_name = primary_name;
-        self.urls = urls
-            .into_iter()
-            .map(|mut url| {
-                if url.name.is_empty() {
-                    url.name = Cow::Owned(String::from(&url.url[..]));
-
-                    url
-                } else {
-                    url
-                }
-            })
-            .collect();
+    /// Sets the URLs for the OpenAPI specifications, like [`Config::urls`],
+    /// checking them first.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`InvalidConfigError`] if more than one URL is marked as
+    /// primary. The previously set URLs are kept in that case.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use swagger_ui_redist::{Config, Url};
+    /// let mut config = Config::new();
+    ///
+    /// assert!(
+    ///     config
+    ///         .try_urls([
+    ///             Url::new("v1", "/api-docs/openapi.json").primary(true),
+    ///             Url::new("v2", "/api-docs/openapi-v2.json").primary(true),
+    ///         ])
+    ///         .is_err()
+    /// );
+    /// ```
+    pub fn try_urls<I: IntoIterator<Item = U>, U: Into<Url>>(
+        &mut self,
+        urls: I,
+    ) -> Result<&mut Self, InvalidConfigError> {
+        let specs = Specs::from_urls(urls);
+        validate(&specs)?;
+        self.specs = specs;
+        Ok(self)
     }
 
-    fn single_url(&mut self, mut urls: Vec<Url<'a>>) {
-        let url = urls.get_mut(0).map(mem::take).unwrap();
-        let primary_name = if url.primary {
-            Some(url.name.to_string())
-        } else {
-            None
-        };
-
-        self.urls_primary_name = primary_name;
-        self.url = if url.name.is_empty() {
-            Some(url.url.to_string())
-        } else {
-            None
-        };
-        self.urls = if url.name.is_empty() {
-            Vec::new()
-        } else {
-            vec![url]
-        };
+    /// Checks that the config is valid, so that [`SwaggerUi::serve`] doesn't
+    /// return an [`InvalidConfigError`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`InvalidConfigError`] if more than one URL is marked as
+    /// primary.
+    pub fn validate(&self) -> Result<(), InvalidConfigError> {
+        validate(&self.specs)
     }
 
     /// Constructs a new [`Config`] from [`Iterator`] of [`Url`]s.
@@ -738,7 +1954,7 @@ impl<'a> Config<'a> {
     /// config.query_config_enabled(true);
     /// ```
     pub fn query_config_enabled(&mut self, query_config_enabled: bool) -> &mut Self {
-        self.query_config_enabled = Some(query_config_enabled);
+        self.options.query_config_enabled = Some(query_config_enabled);
 
         self
     }
@@ -760,7 +1976,7 @@ impl<'a> Config<'a> {
     /// config.deep_linking(false);
     /// ```
     pub fn deep_linking(&mut self, deep_linking: bool) -> &mut Self {
-        self.deep_linking = Some(deep_linking);
+        self.options.deep_linking = Some(deep_linking);
 
         self
     }
@@ -779,7 +1995,7 @@ impl<'a> Config<'a> {
     /// config.display_operation_id(true);
     /// ```
     pub fn display_operation_id(&mut self, display_operation_id: bool) -> &mut Self {
-        self.display_operation_id = Some(display_operation_id);
+        self.options.display_operation_id = Some(display_operation_id);
 
         self
     }
@@ -798,7 +2014,7 @@ impl<'a> Config<'a> {
     /// config.use_base_layout();
     /// ```
     pub fn use_base_layout(&mut self) -> &mut Self {
-        self.layout = SWAGGER_BASE_LAYOUT;
+        self.options.layout = SWAGGER_BASE_LAYOUT;
 
         self
     }
@@ -816,7 +2032,7 @@ impl<'a> Config<'a> {
     /// config.default_models_expand_depth(-1);
     /// ```
     pub fn default_models_expand_depth(&mut self, default_models_expand_depth: isize) -> &mut Self {
-        self.default_models_expand_depth = Some(default_models_expand_depth);
+        self.options.default_models_expand_depth = Some(default_models_expand_depth);
 
         self
     }
@@ -831,7 +2047,7 @@ impl<'a> Config<'a> {
     /// config.default_model_expand_depth(1);
     /// ```
     pub fn default_model_expand_depth(&mut self, default_model_expand_depth: isize) -> &mut Self {
-        self.default_model_expand_depth = Some(default_model_expand_depth);
+        self.options.default_model_expand_depth = Some(default_model_expand_depth);
 
         self
     }
@@ -856,7 +2072,7 @@ impl<'a> Config<'a> {
         &mut self,
         default_model_rendering: S,
     ) -> &mut Self {
-        self.default_model_rendering = Some(default_model_rendering.into());
+        self.options.default_model_rendering = Some(default_model_rendering.into());
 
         self
     }
@@ -874,7 +2090,7 @@ impl<'a> Config<'a> {
     /// config.display_request_duration(true);
     /// ```
     pub fn display_request_duration(&mut self, display_request_duration: bool) -> &mut Self {
-        self.display_request_duration = Some(display_request_duration);
+        self.options.display_request_duration = Some(display_request_duration);
 
         self
     }
@@ -894,7 +2110,7 @@ impl<'a> Config<'a> {
     /// config.doc_expansion(r#"["list"*, "full", "none"]"#);
     /// ```
     pub fn doc_expansion<S: Into<String>>(&mut self, doc_expansion: S) -> &mut Self {
-        self.doc_expansion = Some(doc_expansion.into());
+        self.options.doc_expansion = Some(doc_expansion.into());
 
         self
     }
@@ -916,7 +2132,7 @@ impl<'a> Config<'a> {
     /// config.filter(true);
     /// ```
     pub fn filter(&mut self, filter: bool) -> &mut Self {
-        self.filter = Some(filter);
+        self.options.filter = Some(filter);
 
         self
     }
@@ -934,7 +2150,7 @@ impl<'a> Config<'a> {
     /// config.max_displayed_tags(4);
     /// ```
     pub fn max_displayed_tags(&mut self, max_displayed_tags: usize) -> &mut Self {
-        self.max_displayed_tags = Some(max_displayed_tags);
+        self.options.max_displayed_tags = Some(max_displayed_tags);
 
         self
     }
@@ -952,7 +2168,7 @@ impl<'a> Config<'a> {
     /// config.show_extensions(true);
     /// ```
     pub fn show_extensions(&mut self, show_extensions: bool) -> &mut Self {
-        self.show_extensions = Some(show_extensions);
+        self.options.show_extensions = Some(show_extensions);
 
         self
     }
@@ -970,7 +2186,7 @@ impl<'a> Config<'a> {
     /// config.show_common_extensions(true);
     /// ```
     pub fn show_common_extensions(&mut self, show_common_extensions: bool) -> &mut Self {
-        self.show_common_extensions = Some(show_common_extensions);
+        self.options.show_common_extensions = Some(show_common_extensions);
 
         self
     }
@@ -989,7 +2205,7 @@ impl<'a> Config<'a> {
     /// config.try_it_out_enabled(true);
     /// ```
     pub fn try_it_out_enabled(&mut self, try_it_out_enabled: bool) -> &mut Self {
-        self.try_it_out_enabled = Some(try_it_out_enabled);
+        self.options.try_it_out_enabled = Some(try_it_out_enabled);
 
         self
     }
@@ -1009,7 +2225,7 @@ impl<'a> Config<'a> {
     /// config.request_snippets_enabled(true);
     /// ```
     pub fn request_snippets_enabled(&mut self, request_snippets_enabled: bool) -> &mut Self {
-        self.request_snippets_enabled = Some(request_snippets_enabled);
+        self.options.request_snippets_enabled = Some(request_snippets_enabled);
 
         self
     }
@@ -1025,7 +2241,7 @@ impl<'a> Config<'a> {
     /// config.oauth2_redirect_url("http://my.oauth2.redirect.url");
     /// ```
     pub fn oauth2_redirect_url<S: Into<String>>(&mut self, oauth2_redirect_url: S) -> &mut Self {
-        self.oauth2_redirect_url = Some(oauth2_redirect_url.into());
+        self.options.oauth2_redirect_url = Some(oauth2_redirect_url.into());
 
         self
     }
@@ -1044,7 +2260,7 @@ impl<'a> Config<'a> {
     /// config.show_mutated_request(true);
     /// ```
     pub fn show_mutated_request(&mut self, show_mutated_request: bool) -> &mut Self {
-        self.show_mutated_request = Some(show_mutated_request);
+        self.options.show_mutated_request = Some(show_mutated_request);
 
         self
     }
@@ -1079,7 +2295,7 @@ impl<'a> Config<'a> {
         &mut self,
         supported_submit_methods: I,
     ) -> &mut Self {
-        self.supported_submit_methods = Some(
+        self.options.supported_submit_methods = Some(
             supported_submit_methods
                 .into_iter()
                 .map(Into::into)
@@ -1106,7 +2322,7 @@ impl<'a> Config<'a> {
     /// config.validator_url("none");
     /// ```
     pub fn validator_url<S: Into<String>>(&mut self, validator_url: S) -> &mut Self {
-        self.validator_url = Some(validator_url.into());
+        self.options.validator_url = Some(validator_url.into());
 
         self
     }
@@ -1128,7 +2344,7 @@ impl<'a> Config<'a> {
     /// config.with_credentials(true);
     /// ```
     pub fn with_credentials(&mut self, with_credentials: bool) -> &mut Self {
-        self.with_credentials = Some(with_credentials);
+        self.options.with_credentials = Some(with_credentials);
 
         self
     }
@@ -1148,7 +2364,7 @@ impl<'a> Config<'a> {
     /// config.persist_authorization(true);
     /// ```
     pub fn persist_authorization(&mut self, persist_authorization: bool) -> &mut Self {
-        self.persist_authorization = Some(persist_authorization);
+        self.options.persist_authorization = Some(persist_authorization);
 
         self
     }
@@ -1172,7 +2388,7 @@ impl<'a> Config<'a> {
         &mut self,
         syntax_highlight: H,
     ) -> &mut Self {
-        self.syntax_highlight = Some(syntax_highlight.into());
+        self.options.syntax_highlight = Some(syntax_highlight.into());
 
         self
     }
@@ -1195,20 +2411,216 @@ impl<'a> Config<'a> {
     /// });
     /// ```
     pub fn basic_auth(&mut self, basic_auth: BasicAuth) -> &mut Self {
-        self.basic_auth = Some(basic_auth);
+        self.options.basic_auth = Some(basic_auth);
+
+        self
+    }
+
+    /// Authorizes given API key security scheme with the key once the spec is
+    /// loaded, so the users don't have to enter it themselves.
+    ///
+    /// Note that the key is visible to anyone who can see the page.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use swagger_ui_redist::Config;
+    /// let mut config = Config::new();
+    /// config.preauthorize_api_key("api_key", "abcde12345");
+    /// ```
+    pub fn preauthorize_api_key(
+        &mut self,
+        scheme: impl Into<String>,
+        key: impl Into<String>,
+    ) -> &mut Self {
+        self.preauthorizations
+            .push(Preauthorization::api_key(scheme.into(), key.into()));
+        self
+    }
+
+    /// Authorizes given HTTP basic security scheme with the credentials once
+    /// the spec is loaded, so the users don't have to enter them themselves.
+    ///
+    /// Note that the credentials are visible to anyone who can see the page.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use swagger_ui_redist::Config;
+    /// let mut config = Config::new();
+    /// config.preauthorize_basic("basic_auth", "demo", "demo");
+    /// ```
+    pub fn preauthorize_basic(
+        &mut self,
+        scheme: impl Into<String>,
+        username: impl Into<String>,
+        password: impl Into<String>,
+    ) -> &mut Self {
+        self.preauthorizations.push(Preauthorization::basic(
+            scheme.into(),
+            username.into(),
+            password.into(),
+        ));
+        self
+    }
+}
+
+/// Settings overriding the ones of [`SwaggerUi`] and its [`Config`] when
+/// rendering a single page with [`SwaggerUi::serve_with`].
+///
+/// This makes it possible to share a single [`SwaggerUi`] between e.g.
+/// multiple tenants, each seeing their own specs or OAuth client, without
+/// cloning or mutating it for every request.
+///
+/// # Examples
+///
+/// ```
+/// # use swagger_ui_redist::{ConfigOverrides, SwaggerUi, oauth};
+/// let mut swagger = SwaggerUi::new();
+/// swagger.config().urls(["/api-docs/openapi.json"]);
+///
+/// let tenant = "acme";
+/// let mut overrides = ConfigOverrides::new();
+/// overrides
+///     .title(format!("{tenant} API"))
+///     .urls([format!("/{tenant}/api-docs/openapi.json")])
+///     .oauth_config(oauth::Config::new().client_id(tenant).clone());
+///
+/// let html = swagger.serve_with(&overrides)?;
+/// assert!(html.contains("<title>acme API</title>"));
+/// # Ok::<(), Box<dyn std::error::Error + Send + Sync>>(())
+/// ```
+#[non_exhaustive]
+#[derive(Debug, Default, Clone)]
+pub struct ConfigOverrides {
+    title: Option<Cow<'static, str>>,
+    specs: Option<Specs>,
+    oauth: Option<oauth::Config>,
+    preauthorizations: Vec<Preauthorization>,
+}
+
+impl ConfigOverrides {
+    /// Creates new [`ConfigOverrides`], which don't override anything.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Overrides the title of the page, see [`SwaggerUi::title`].
+    pub fn title(&mut self, title: impl Into<Cow<'static, str>>) -> &mut Self {
+        self.title = Some(title.into());
+        self
+    }
+
+    /// Overrides the URLs of the OpenAPI specs, see [`Config::urls`].
+    pub fn urls<I: IntoIterator<Item = U>, U: Into<Url>>(&mut self, urls: I) -> &mut Self {
+        self.specs = Some(Specs::from_urls(urls));
+        self
+    }
+
+    /// Overrides the OAuth config, see [`Config::oauth_config`].
+    pub fn oauth_config(&mut self, oauth_config: oauth::Config) -> &mut Self {
+        self.oauth = Some(oauth_config);
+        self
+    }
+
+    /// Authorizes given API key security scheme with the key, in addition to
+    /// (or instead of, for the same scheme) the ones set with
+    /// [`Config::preauthorize_api_key`].
+    pub fn preauthorize_api_key(
+        &mut self,
+        scheme: impl Into<String>,
+        key: impl Into<String>,
+    ) -> &mut Self {
+        self.preauthorizations
+            .push(Preauthorization::api_key(scheme.into(), key.into()));
+        self
+    }
 
+    /// Authorizes given HTTP basic security scheme with the credentials, in
+    /// addition to (or instead of, for the same scheme) the ones set with
+    /// [`Config::preauthorize_basic`].
+    pub fn preauthorize_basic(
+        &mut self,
+        scheme: impl Into<String>,
+        username: impl Into<String>,
+        password: impl Into<String>,
+    ) -> &mut Self {
+        self.preauthorizations.push(Preauthorization::basic(
+            scheme.into(),
+            username.into(),
+            password.into(),
+        ));
         self
     }
 }
 
-impl Default for Config<'_> {
+/// A security scheme authorized as soon as the spec is loaded.
+#[derive(Debug, Clone)]
+enum Preauthorization {
+    ApiKey {
+        scheme: String,
+        key: String,
+    },
+    Basic {
+        scheme: String,
+        username: String,
+        password: String,
+    },
+}
+
+impl Preauthorization {
+    fn api_key(scheme: String, key: String) -> Self {
+        Self::ApiKey { scheme, key }
+    }
+
+    fn basic(scheme: String, username: String, password: String) -> Self {
+        Self::Basic {
+            scheme,
+            username,
+            password,
+        }
+    }
+
+    /// Returns the JavaScript statement authorizing the scheme.
+    fn script(&self, format: ConfigFormat) -> serde_json::Result<String> {
+        let (function, arguments) = match self {
+            Self::ApiKey { scheme, key } => ("preauthorizeApiKey", vec![scheme, key]),
+            Self::Basic {
+                scheme,
+                username,
+                password,
+            } => ("preauthorizeBasic", vec![scheme, username, password]),
+        };
+        let arguments = arguments
+            .into_iter()
+            .map(serde_json::to_string)
+            .collect::<serde_json::Result<Vec<_>>>()?;
+        let separator = match format {
+            ConfigFormat::Pretty => ", ",
+            ConfigFormat::Minified => ",",
+        };
+
+        Ok(format!("ui.{function}({});", arguments.join(separator)))
+    }
+}
+
+impl Default for Config {
     fn default() -> Self {
         Self {
             config_url: Option::default(),
             dom_id: Some("#swagger-ui".to_string()),
-            url: Option::default(),
-            urls_primary_name: Option::default(),
-            urls: Vec::default(),
+            specs: Specs::default(),
+            options: Options::default(),
+            oauth: Option::default(),
+            preauthorizations: Vec::default(),
+        }
+    }
+}
+
+impl Default for Options {
+    fn default() -> Self {
+        Self {
             query_config_enabled: Option::default(),
             deep_linking: Some(true),
             display_operation_id: Option::default(),
@@ -1229,7 +2641,6 @@ impl Default for Config<'_> {
             validator_url: Option::default(),
             with_credentials: Option::default(),
             persist_authorization: Option::default(),
-            oauth: Option::default(),
             syntax_highlight: Option::default(),
             layout: SWAGGER_STANDALONE_LAYOUT,
             basic_auth: Option::default(),
@@ -1291,6 +2702,9 @@ impl SyntaxHighlight {
     /// Explicitly specifies the
     /// [Highlight.js](https://highlightjs.org/) coloring theme to
     /// utilize for syntax highlighting.
+    ///
+    /// Swagger UI 4 supports the `agate`, `arta`, `monokai`, `nord`,
+    /// `obsidian` and `tomorrow-night` themes; Swagger UI 5 adds `idea`.
     #[must_use]
     pub fn theme(mut self, theme: &'static str) -> Self {
         self.theme = Some(theme);
@@ -1298,8 +2712,8 @@ impl SyntaxHighlight {
     }
 }
 
-/// Represents servable file of Swagger UI. This is used together with [`serve`]
-/// function to serve Swagger UI files via web server.
+/// Represents servable file of Swagger UI. This is returned by
+/// [`SwaggerUi::file`] to serve Swagger UI files via web server.
 #[non_exhaustive]
 #[derive(Debug)]
 pub struct SwaggerFile<'a> {
@@ -1307,18 +2721,225 @@ pub struct SwaggerFile<'a> {
     pub bytes: Cow<'a, [u8]>,
     /// Content type of the file e.g `"text/xml"`.
     pub content_type: String,
+    /// Strong entity tag of the file, including the surrounding double quotes
+    /// (e.g. `"5d41402abc4b2a76"`).
+    pub etag: String,
+}
+
+impl SwaggerFile<'_> {
+    /// Creates a response to a (possibly) range request for this file.
+    ///
+    /// `range` and `if_range` are the values of the `Range` and `If-Range`
+    /// request headers, if present. See the [`range`] module for more details.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use swagger_ui_redist::SwaggerUi;
+    /// let swagger = SwaggerUi::new();
+    /// let file = swagger.file("/swagger-ui-bundle.js")?.unwrap();
+    ///
+    /// let response = file.range_response(Some("bytes=-100"), Some(&file.etag));
+    /// assert_eq!(response.status, 206);
+    /// assert_eq!(response.body.len(), 100);
+    /// # Ok::<(), Box<dyn std::error::Error + Send + Sync>>(())
+    /// ```
+    #[must_use]
+    pub fn range_response(
+        &self,
+        range: Option<&str>,
+        if_range: Option<&str>,
+    ) -> range::RangeResponse<'_> {
+        range::respond(&self.bytes, &self.content_type, &self.etag, range, if_range)
+    }
+}
+
+/// Computes a strong entity tag for given content using the 64-bit FNV-1a
+/// hash, which is stable across builds and platforms.
+fn etag(bytes: &[u8]) -> String {
+    const FNV_OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+    const FNV_PRIME: u64 = 0x0000_0100_0000_01b3;
+
+    let hash = bytes.iter().fold(FNV_OFFSET_BASIS, |hash, byte| {
+        (hash ^ u64::from(*byte)).wrapping_mul(FNV_PRIME)
+    });
+
+    format!("\"{hash:016x}\"")
+}
+
+/// An error returned when the [`Config`] is invalid.
+#[non_exhaustive]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InvalidConfigError {
+    /// More than one [`Url`] is marked as primary.
+    MultiplePrimaryUrls {
+        /// The names of the URLs marked as primary.
+        names: Vec<String>,
+    },
+}
+
+impl Display for InvalidConfigError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            InvalidConfigError::MultiplePrimaryUrls { names } => write!(
+                f,
+                "only one URL can be marked as primary, but {} are: {}",
+                names.len(),
+                names.join(", ")
+            ),
+        }
+    }
+}
+
+impl Error for InvalidConfigError {}
+
+/// Checks that the specs are valid.
+fn validate(specs: &Specs) -> Result<(), InvalidConfigError> {
+    let primary_names = specs
+        .urls
+        .iter()
+        .filter(|url| url.primary)
+        .map(|url| url.name.clone())
+        .collect::<Vec<_>>();
+
+    if primary_names.len() > 1 {
+        return Err(InvalidConfigError::MultiplePrimaryUrls {
+            names: primary_names,
+        });
+    }
+
+    Ok(())
+}
+
+/// An error returned when the [`Config`] uses a feature that is not supported
+/// by the bundled version of Swagger UI (see [`SwaggerUi::VERSION`]).
+#[non_exhaustive]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum IncompatibleConfigError {
+    /// The embedded OpenAPI spec uses OpenAPI 3.1, which can only be rendered
+    /// by Swagger UI 5.
+    OpenApi31 {
+        /// The version declared in the `openapi` field of the spec.
+        version: String,
+    },
+    /// The syntax highlighting theme set with [`SyntaxHighlight::theme`] is
+    /// only available in Swagger UI 5.
+    SyntaxHighlightTheme {
+        /// The name of the theme.
+        theme: &'static str,
+    },
+}
+
+impl Display for IncompatibleConfigError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            IncompatibleConfigError::OpenApi31 { version } => write!(
+                f,
+                "OpenAPI {version} requires Swagger UI 5, but Swagger UI {} is bundled",
+                SwaggerUi::VERSION
+            ),
+            IncompatibleConfigError::SyntaxHighlightTheme { theme } => write!(
+                f,
+                "syntax highlighting theme {theme:?} requires Swagger UI 5, but Swagger UI {} is bundled",
+                SwaggerUi::VERSION
+            ),
+        }
+    }
+}
+
+impl Error for IncompatibleConfigError {}
+
+/// Checks that the config only uses features supported by the bundled
+/// version of Swagger UI.
+///
+/// All the options of [`Config`] and [`oauth::Config`] are understood by both
+/// Swagger UI 4 and 5 (`requestSnippetsEnabled`, the newest of them, exists
+/// since Swagger UI 3.48), so only their values need checking: the embedded
+/// spec, and the syntax highlighting themes added in Swagger UI 5.
+fn check_compatibility(config: &Config, specs: &Specs) -> Result<(), IncompatibleConfigError> {
+    /// Syntax highlighting themes only available in Swagger UI 5.
+    const V5_THEMES: &[&str] = &["idea"];
+
+    if cfg!(feature = "v4") {
+        let openapi_version = specs
+            .spec
+            .as_ref()
+            .and_then(|spec| spec.get("openapi"))
+            .and_then(serde_json::Value::as_str);
+        if let Some(version) = openapi_version.filter(|version| version.starts_with("3.1")) {
+            return Err(IncompatibleConfigError::OpenApi31 {
+                version: version.to_owned(),
+            });
+        }
+
+        let theme = config
+            .options
+            .syntax_highlight
+            .as_ref()
+            .and_then(|syntax_highlight| syntax_highlight.theme);
+        if let Some(theme) = theme.filter(|theme| V5_THEMES.contains(theme)) {
+            return Err(IncompatibleConfigError::SyntaxHighlightTheme { theme });
+        }
+    }
+
+    Ok(())
 }
 
 #[inline]
-fn format_config(config: &Config<'_>, file: &str) -> Result<String, Box<dyn Error + Send + Sync>> {
-    let config_json = match serde_json::to_string_pretty(&config) {
-        Ok(config) => config,
-        Err(error) => return Err(Box::new(error)),
-    };
+fn format_config(
+    config: &Config,
+    overrides: &ConfigOverrides,
+    format: ConfigFormat,
+    file: &str,
+) -> Result<String, Box<dyn Error + Send + Sync>> {
+    let specs = overrides.specs.as_ref().unwrap_or(&config.specs);
+    validate(specs)?;
+    check_compatibility(config, specs)?;
+
+    // {{config}} is in the middle of an object literal, so only the entries of
+    // the config object are inserted, followed by the JavaScript ones
+    let config_json = format.to_json(&ConfigJson::new(config, specs))?;
+    let mut entries = object_entries(&config_json).to_owned();
+    let mut preauthorizations = config
+        .preauthorizations
+        .iter()
+        .chain(&overrides.preauthorizations)
+        .peekable();
+    if preauthorizations.peek().is_some() {
+        let (start, indent, newline, end) = match format {
+            ConfigFormat::Pretty => (",\n  onComplete: () => {\n", "    ", "\n", "  }"),
+            ConfigFormat::Minified => (",onComplete:()=>{", "", "", "}"),
+        };
+        entries.push_str(start);
+        for preauthorization in preauthorizations {
+            entries.push_str(indent);
+            entries.push_str(&preauthorization.script(format)?);
+            entries.push_str(newline);
+        }
+        entries.push_str(end);
+    }
+    let mut script = file.replace("{{config}}", &entries);
+
+    if let Some(oauth) = overrides.oauth.as_ref().or(config.oauth.as_ref()) {
+        script.push_str("\nui.initOAuth(");
+        script.push_str(&format.to_json(oauth)?);
+        script.push_str(");");
+    }
+
+    // neither the specs nor the overrides may end the script
+    Ok(script.replace("</", r"<\/"))
+}
 
-    // Replace {{config}} with pretty config json and remove the curly brackets `{
-    // }` from beginning and the end.
-    Ok(file.replace("{{config}}", &config_json[2..&config_json.len() - 2]))
+/// Returns the entries of given JSON object, i.e. the object without the
+/// surrounding braces and line breaks. The indentation of the first entry is
+/// kept, so pretty-printed entries stay indented in the page.
+fn object_entries(json: &str) -> &str {
+    json.trim()
+        .strip_prefix('{')
+        .and_then(|json| json.strip_suffix('}'))
+        .expect("JSON should be an object")
+        .trim_start_matches(['\r', '\n'])
+        .trim_end()
 }
 
 const DEFAULT_CONFIG: &str = r"
@@ -1357,6 +2978,373 @@ window.ui = SwaggerUIBundle({
   ],
 });";
 
+    #[test]
+    fn base64_encode_padding() {
+        assert_eq!(base64_encode(b""), "");
+        assert_eq!(base64_encode(b"f"), "Zg==");
+        assert_eq!(base64_encode(b"fo"), "Zm8=");
+        assert_eq!(base64_encode(b"foo"), "Zm9v");
+        assert_eq!(base64_encode(b"foob"), "Zm9vYg==");
+        assert_eq!(base64_encode(&[0xff, 0xfe, 0xfd]), "//79");
+    }
+
+    #[test]
+    fn serve_standalone_inlines_static_files() {
+        let mut swagger = SwaggerUi::new();
+        swagger.config().urls(["/api-docs/openapi.json"]);
+
+        let html = swagger.serve_standalone().unwrap();
+
+        for static_file in SwaggerUiStaticFile::all() {
+            assert!(!html.contains(&StaticFile::default_path(static_file)));
+        }
+        assert!(html.contains("<style>"));
+        assert!(html.contains("href=\"data:image/png;base64,"));
+        assert!(html.contains("SwaggerUIBundle"));
+        assert!(html.contains("\"url\": \"/api-docs/openapi.json\""));
+    }
+
+    #[test]
+    fn inline_assets_escape_end_tags() {
+        assert_eq!(
+            inline_script(b"a = '</script>'; b = '</SCRIPT >'; c = '</ScRiPt';"),
+            "<script charset=\"UTF-8\">\na = '<\\/script>'; b = '<\\/SCRIPT >'; c = '<\\/ScRiPt';\n</script>"
+        );
+        assert_eq!(
+            inline_stylesheet(b"/* </Style> </div> */"),
+            "<style>\n/* <\\/Style> </div> */\n</style>"
+        );
+    }
+
+    #[test]
+    fn serve_standalone_with_spec_embeds_spec() {
+        let mut swagger = SwaggerUi::new();
+        swagger.config().urls(["/api-docs/openapi.json"]);
+
+        let html = swagger
+            .serve_standalone_with_spec(r#"{"info": {"description": "</script><b>"}}"#)
+            .unwrap();
+
+        assert!(!html.contains("/api-docs/openapi.json"));
+        assert!(html.contains(r#""description": "<\/script><b>""#));
+    }
+
+    #[test]
+    fn serve_standalone_with_spec_openapi_31() {
+        let swagger = SwaggerUi::new();
+
+        let result = swagger.serve_standalone_with_spec(r#"{"openapi": "3.1.0"}"#);
+
+        if cfg!(feature = "v4") {
+            let error = result.unwrap_err();
+            assert_eq!(
+                error.downcast_ref::<IncompatibleConfigError>(),
+                Some(&IncompatibleConfigError::OpenApi31 {
+                    version: "3.1.0".to_owned()
+                })
+            );
+        } else {
+            assert!(result.unwrap().contains(r#""openapi": "3.1.0""#));
+        }
+    }
+
+    #[test]
+    fn syntax_highlight_theme_compatibility() {
+        let mut swagger = SwaggerUi::new();
+        swagger
+            .config()
+            .with_syntax_highlight(SyntaxHighlight::default().theme("idea"));
+
+        let result = swagger.serve();
+
+        if cfg!(feature = "v4") {
+            let error = result.unwrap_err();
+            assert_eq!(
+                error.downcast_ref::<IncompatibleConfigError>(),
+                Some(&IncompatibleConfigError::SyntaxHighlightTheme { theme: "idea" })
+            );
+        } else {
+            assert!(result.unwrap().contains(r#""theme": "idea""#));
+        }
+
+        swagger
+            .config()
+            .with_syntax_highlight(SyntaxHighlight::default().theme("monokai"))
+            .request_snippets_enabled(true);
+        assert!(swagger.serve().is_ok());
+    }
+
+    #[test]
+    fn serve_custom_template() {
+        let mut swagger = SwaggerUi::new();
+        swagger.title("My API").config().urls(["/openapi.json"]);
+        *swagger.template() =
+            Template::custom("<title>{{title}}</title>{{js}}<script>{{config}}</script>");
+
+        let html = swagger.serve().unwrap();
+
+        assert!(html.starts_with(
+            r#"<title>My API</title><script src="./swagger-ui-bundle.js" charset="UTF-8"></script><script>"#
+        ));
+        assert!(html.contains(r#""url": "/openapi.json""#));
+    }
+
+    #[test]
+    fn linked_asset_paths_are_escaped() {
+        let mut swagger = SwaggerUi::new();
+        swagger
+            .add_script("a&b.js", b"")
+            .override_file_path(SwaggerUiStaticFile::Css, "./x'y.css".to_string());
+
+        let html = swagger.serve().unwrap();
+
+        assert!(html.contains(r#"<script src="./a&amp;b.js" charset="UTF-8"></script>"#));
+        assert!(html.contains(r#"href="./x&#39;y.css""#));
+    }
+
+    #[test]
+    #[should_panic(expected = "invalid custom asset file name")]
+    fn custom_asset_with_quote_is_rejected() {
+        SwaggerUi::new().add_script("a\" onload=\"alert(1)", b"");
+    }
+
+    #[test]
+    #[should_panic(expected = "invalid custom asset file name")]
+    fn custom_asset_with_parent_segment_is_rejected() {
+        SwaggerUi::new().add_stylesheet("css/../../secret.css", &b""[..]);
+    }
+
+    #[test]
+    fn serve_escapes_title() {
+        let mut swagger = SwaggerUi::new();
+        swagger.title("Q&A </title><script>");
+
+        let html = swagger.serve().unwrap();
+
+        assert!(html.contains("<title>Q&amp;A &lt;/title&gt;&lt;script&gt;</title>"));
+    }
+
+    #[test]
+    fn custom_assets_after_built_in_ones() {
+        let mut swagger = SwaggerUi::new();
+        swagger
+            .add_stylesheet("./brand.css", ":root { --brand: red; }".as_bytes())
+            .add_script("extra.js", b"window.extra = true;");
+
+        let html = swagger.serve().unwrap();
+        let index_css = html.find(r#"href="./index.css""#).unwrap();
+        let brand_css = html.find(r#"href="./brand.css""#).unwrap();
+        let preset_js = html
+            .find(r#"src="./swagger-ui-standalone-preset.js""#)
+            .unwrap();
+        let extra_js = html.find(r#"src="./extra.js""#).unwrap();
+        assert!(index_css < brand_css);
+        assert!(preset_js < extra_js && extra_js < html.find("window.onload").unwrap());
+
+        let standalone = swagger.serve_standalone().unwrap();
+        assert!(standalone.contains("<style>\n:root { --brand: red; }\n</style>"));
+        assert!(standalone.contains("<script charset=\"UTF-8\">\nwindow.extra = true;\n</script>"));
+
+        let brand_css = swagger.file("brand.css").unwrap().unwrap();
+        assert_eq!(brand_css.content_type, "text/css; charset=utf-8");
+        assert_eq!(brand_css.bytes.as_ref(), b":root { --brand: red; }");
+        assert_eq!(
+            swagger.file("/extra.js").unwrap().unwrap().content_type,
+            "text/javascript; charset=utf-8"
+        );
+    }
+
+    #[test]
+    fn dark_theme_is_referenced() {
+        let mut swagger = SwaggerUi::new();
+        assert!(!swagger.serve().unwrap().contains("swagger-ui-dark"));
+
+        swagger
+            .theme(Theme::System)
+            .add_stylesheet("custom.css", b"".as_slice());
+        let html = swagger.serve().unwrap();
+        let dark_css = html.find(r#"href="./swagger-ui-dark.css""#).unwrap();
+        assert!(html.find(r#"href="./index.css""#).unwrap() < dark_css);
+        assert!(dark_css < html.find(r#"href="./custom.css""#).unwrap());
+        assert!(html.contains(
+            r#"<script src="./swagger-ui-dark.js" data-default-theme="system" charset="UTF-8"></script>"#
+        ));
+
+        swagger.theme(Theme::Dark);
+        let standalone = swagger.serve_standalone().unwrap();
+        assert!(standalone.contains("html.swagger-ui-dark {"));
+        assert!(standalone.contains(r#"<script data-default-theme="dark" charset="UTF-8">"#));
+        assert!(standalone.contains("prefers-color-scheme: dark"));
+
+        let dark_css = swagger.file("swagger-ui-dark.css").unwrap().unwrap();
+        assert_eq!(dark_css.content_type, "text/css; charset=utf-8");
+    }
+
+    #[test]
+    fn branding() {
+        let mut swagger = SwaggerUi::new();
+        swagger
+            .favicon(Image::ico(b"ico".as_slice()))
+            .logo(Image::svg(b"<svg/>".as_slice()))
+            .logo_link("https://example.com/</script>");
+
+        let html = swagger.serve().unwrap();
+        assert!(html.contains(
+            r#"<link rel="icon" type="image/x-icon" href="./favicon.ico" sizes="32x32" />"#
+        ));
+        assert!(!html.contains("favicon-16x16.png"));
+        assert!(html.contains(r#"background: url("./logo.svg")"#));
+        assert!(html.contains(r#"link.href = "https://example.com/<\/script>";"#));
+
+        let standalone = swagger.serve_standalone().unwrap();
+        assert!(standalone.contains(r#"href="data:image/x-icon;base64,aWNv""#));
+        assert!(standalone.contains(r#"url("data:image/svg+xml;base64,PHN2Zy8+")"#));
+
+        let favicon = swagger.file("favicon.ico").unwrap().unwrap();
+        assert_eq!(favicon.content_type, "image/x-icon");
+        assert_eq!(favicon.bytes.as_ref(), b"ico");
+        let logo = swagger.file("/logo.svg").unwrap().unwrap();
+        assert_eq!(logo.content_type, "image/svg+xml");
+        assert!(swagger.file("/logo.png").unwrap().is_none());
+    }
+
+    #[test]
+    fn localization() {
+        let mut translation = Translation::new();
+        translation.label("Execute", "Ausführen");
+        let mut swagger = SwaggerUi::new();
+        swagger.lang("de").translation(translation);
+
+        let html = swagger.serve().unwrap();
+        assert!(html.starts_with("<!DOCTYPE html>\n<html lang=\"de\">\n"));
+        assert!(html.contains(
+            r#"<script type="application/json" id="swagger-ui-translation">{"Execute":"Ausführen"}</script>
+<script src="./swagger-ui-i18n.js" charset="UTF-8"></script>"#
+        ));
+
+        let standalone = swagger.serve_standalone().unwrap();
+        assert!(standalone.contains("getElementById('swagger-ui-translation')"));
+    }
+
+    #[test]
+    fn license_is_served() {
+        let swagger = SwaggerUi::new();
+
+        let license = swagger
+            .file("LICENSE")
+            .unwrap()
+            .expect("license should be served");
+
+        assert_eq!(license.content_type, "text/plain; charset=utf-8");
+        assert_eq!(license.bytes.as_ref(), SwaggerUi::LICENSE.as_bytes());
+        assert!(SwaggerUi::LICENSE.contains("Apache License"));
+    }
+
+    #[test]
+    fn version_matches_bundled_files() {
+        let bundle = String::from_utf8_lossy(SwaggerUiStaticFile::Js.bytes());
+
+        assert!(bundle.contains(&format!("\"{}\"", SwaggerUi::VERSION)));
+    }
+
+    #[test]
+    fn serve_standalone_with_spec_invalid_json() {
+        let swagger = SwaggerUi::new();
+
+        assert!(
+            swagger
+                .serve_standalone_with_spec("openapi: 3.1.0")
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn format_swagger_config_minified() {
+        const EXPECTED: &str = r##"
+window.ui = SwaggerUIBundle({
+  "dom_id":"#swagger-ui","urls":[{"name":"api-doc1","url":"/api-docs/openapi1.json"},{"name":"/api-docs/openapi2.json","url":"/api-docs/openapi2.json"}],"deepLinking":true,"layout":"StandaloneLayout",onComplete:()=>{ui.preauthorizeApiKey("api_key","key");},
+  presets: [
+    SwaggerUIBundle.presets.apis,
+    SwaggerUIStandalonePreset
+  ],
+  plugins: [
+    SwaggerUIBundle.plugins.DownloadUrl
+  ],
+});
+ui.initOAuth({"clientId":"client"});"##;
+
+        let mut config = Config::new();
+        config
+            .urls([
+                Url::new("api-doc1", "/api-docs/openapi1.json"),
+                Url::from("/api-docs/openapi2.json"),
+            ])
+            .oauth_config(oauth::Config::new().client_id("client").clone())
+            .preauthorize_api_key("api_key", "key");
+
+        let formatted_config = format_config(
+            &config,
+            &ConfigOverrides::new(),
+            ConfigFormat::Minified,
+            TEST_INITIAL_CONFIG,
+        )
+        .unwrap();
+
+        assert_diff_equal(EXPECTED, &formatted_config);
+    }
+
+    #[test]
+    fn format_swagger_config_pretty_and_minified_are_equivalent() {
+        let mut config = Config::new();
+        config
+            .urls(["/api-docs/openapi1.json", "/api-docs/openapi2.json"])
+            .supported_submit_methods(["get", "post"])
+            .with_syntax_highlight(SyntaxHighlight::default().theme("monokai"))
+            .basic_auth(BasicAuth {
+                username: "admin".to_string(),
+                password: "</script>".to_string(),
+            });
+        let overrides = ConfigOverrides::new();
+
+        let [pretty, minified] = [ConfigFormat::Pretty, ConfigFormat::Minified].map(|format| {
+            let object = format_config(&config, &overrides, format, "{{{config}}}").unwrap();
+            serde_json::from_str::<serde_json::Value>(&object).unwrap()
+        });
+
+        assert_eq!(pretty, minified);
+        assert_eq!(pretty["basicAuth"]["password"], "</script>");
+    }
+
+    #[test]
+    fn object_entries_keeps_indentation() {
+        assert_eq!(object_entries(r#"{"a":1}"#), r#""a":1"#);
+        assert_eq!(
+            object_entries("{\n  \"a\": 1,\n  \"b\": {}\n}"),
+            "  \"a\": 1,\n  \"b\": {}"
+        );
+        assert_eq!(object_entries(" {} "), "");
+    }
+
+    #[test]
+    fn serve_minified_config() {
+        let mut swagger = SwaggerUi::new();
+        swagger
+            .config_format(ConfigFormat::Minified)
+            .config()
+            .urls(["/api-docs/openapi.json"]);
+
+        let html = swagger.serve().unwrap();
+        assert!(html.contains(
+            r##"  "dom_id":"#swagger-ui","url":"/api-docs/openapi.json","deepLinking":true,"layout":"StandaloneLayout",
+  presets: ["##
+        ));
+
+        let standalone = swagger
+            .serve_standalone_with_spec(r#"{"openapi": "3.0.3", "paths": {}}"#)
+            .unwrap();
+        assert!(standalone.contains(r#""spec":{"openapi":"3.0.3","paths":{}}"#));
+    }
+
     #[test]
     fn format_swagger_config_json_single_url() {
         const EXPECTED: &str = r##"
@@ -1376,6 +3364,8 @@ window.ui = SwaggerUIBundle({
 
         let formatted_config = match format_config(
             Config::new().urls(["/api-docs/openapi1.json"]),
+            &ConfigOverrides::new(),
+            ConfigFormat::Pretty,
             TEST_INITIAL_CONFIG,
         ) {
             Ok(file) => file,
@@ -1409,6 +3399,8 @@ window.ui = SwaggerUIBundle({
 
         let formatted_config = match format_config(
             Config::new().urls([Url::new("api-doc1", "/api-docs/openapi1.json")]),
+            &ConfigOverrides::new(),
+            ConfigFormat::Pretty,
             TEST_INITIAL_CONFIG,
         ) {
             Ok(file) => file,
@@ -1447,6 +3439,8 @@ window.ui = SwaggerUIBundle({
                 "/api-docs/openapi1.json",
                 true,
             )]),
+            &ConfigOverrides::new(),
+            ConfigFormat::Pretty,
             TEST_INITIAL_CONFIG,
         ) {
             Ok(file) => file,
@@ -1488,6 +3482,8 @@ window.ui = SwaggerUIBundle({
                 Url::with_primary("api-doc1", "/api-docs/openapi1.json", true),
                 Url::new("api-doc2", "/api-docs/openapi2.json"),
             ]),
+            &ConfigOverrides::new(),
+            ConfigFormat::Pretty,
             TEST_INITIAL_CONFIG,
         ) {
             Ok(file) => file,
@@ -1497,6 +3493,287 @@ window.ui = SwaggerUIBundle({
         assert_diff_equal(EXPECTED, &formatted_config);
     }
 
+    /// Returns the `url`, `urls.primaryName` and `urls` (as name/URL pairs)
+    /// of the config after setting given URLs.
+    fn url_fields(urls: Vec<Url>) -> (Option<String>, Option<String>, Vec<(String, String)>) {
+        let mut config = Config::new();
+        config.urls(urls);
+
+        (
+            config.specs.url,
+            config.specs.urls_primary_name,
+            config
+                .specs
+                .urls
+                .into_iter()
+                .map(|url| (url.name, url.url))
+                .collect(),
+        )
+    }
+
+    fn pair(name: &str, url: &str) -> (String, String) {
+        (name.to_owned(), url.to_owned())
+    }
+
+    #[test]
+    fn urls_single() {
+        let single = Some("/a.json".to_owned());
+
+        assert_eq!(
+            url_fields(vec![Url::from("/a.json")]),
+            (single.clone(), None, vec![])
+        );
+        assert_eq!(
+            url_fields(vec![Url::from("/a.json").primary(true)]),
+            (single, None, vec![])
+        );
+        assert_eq!(
+            url_fields(vec![Url::new("A", "/a.json")]),
+            (None, None, vec![pair("A", "/a.json")])
+        );
+        assert_eq!(
+            url_fields(vec![Url::new("A", "/a.json").primary(true)]),
+            (None, Some("A".to_owned()), vec![pair("A", "/a.json")])
+        );
+    }
+
+    #[test]
+    fn urls_multiple() {
+        assert_eq!(
+            url_fields(vec![Url::from("/a.json"), Url::from("/b.json")]),
+            (
+                None,
+                None,
+                vec![pair("/a.json", "/a.json"), pair("/b.json", "/b.json")]
+            )
+        );
+        assert_eq!(
+            url_fields(vec![Url::new("A", "/a.json"), Url::new("B", "/b.json")]),
+            (None, None, vec![pair("A", "/a.json"), pair("B", "/b.json")])
+        );
+        assert_eq!(
+            url_fields(vec![
+                Url::new("A", "/a.json"),
+                Url::new("B", "/b.json").primary(true)
+            ]),
+            (
+                None,
+                Some("B".to_owned()),
+                vec![pair("A", "/a.json"), pair("B", "/b.json")]
+            )
+        );
+    }
+
+    #[test]
+    fn urls_multiple_unnamed_primary() {
+        assert_eq!(
+            url_fields(vec![
+                Url::new("A", "/a.json"),
+                Url::from("/b.json").primary(true)
+            ]),
+            (
+                None,
+                Some("/b.json".to_owned()),
+                vec![pair("A", "/a.json"), pair("/b.json", "/b.json")]
+            )
+        );
+    }
+
+    #[test]
+    fn urls_empty() {
+        assert_eq!(url_fields(vec![]), (None, None, vec![]));
+    }
+
+    #[test]
+    fn urls_replace_previous() {
+        let mut config = Config::new();
+        config.urls(["/a.json"]);
+        config.urls([Url::new("B", "/b.json").primary(true)]);
+        assert_eq!(config.specs.url, None);
+
+        config.urls(Vec::<Url>::new());
+        assert_eq!(config.specs.url, None);
+        assert_eq!(config.specs.urls_primary_name, None);
+        assert!(config.specs.urls.is_empty());
+    }
+
+    #[test]
+    fn urls_multiple_primary() {
+        let mut swagger = SwaggerUi::new();
+        swagger.config().urls([
+            Url::new("A", "/a.json").primary(true),
+            Url::new("B", "/b.json"),
+            Url::from("/c.json").primary(true),
+        ]);
+
+        let error = swagger.serve().unwrap_err();
+
+        assert_eq!(
+            error.downcast_ref::<InvalidConfigError>(),
+            Some(&InvalidConfigError::MultiplePrimaryUrls {
+                names: vec!["A".to_owned(), "/c.json".to_owned()]
+            })
+        );
+        assert_eq!(
+            error.to_string(),
+            "only one URL can be marked as primary, but 2 are: A, /c.json"
+        );
+    }
+
+    #[test]
+    fn try_urls_multiple_primary() {
+        let urls = || {
+            [
+                Url::new("A", "/a.json").primary(true),
+                Url::new("B", "/b.json").primary(true),
+            ]
+        };
+        let expected = InvalidConfigError::MultiplePrimaryUrls {
+            names: vec!["A".to_owned(), "B".to_owned()],
+        };
+
+        let mut config = Config::new();
+        config.urls(["/previous.json"]);
+        assert_eq!(config.try_urls(urls()).unwrap_err(), expected);
+        assert_eq!(config.specs.url.as_deref(), Some("/previous.json"));
+        assert_eq!(config.validate(), Ok(()));
+
+        config.urls(urls());
+        assert_eq!(config.validate(), Err(expected));
+
+        config
+            .try_urls([Url::new("A", "/a.json").primary(true)])
+            .unwrap();
+        assert_eq!(config.specs.urls_primary_name.as_deref(), Some("A"));
+    }
+
+    #[test]
+    fn serve_with_overrides() {
+        let mut swagger = SwaggerUi::new();
+        swagger.title("Base API").config().urls(["/base.json"]);
+        let base = swagger.serve().unwrap();
+
+        let mut overrides = ConfigOverrides::new();
+        overrides.title("Tenant API").urls([
+            Url::new("v1", "/v1.json"),
+            Url::new("v2", "/v2.json").primary(true),
+        ]);
+        let html = swagger.serve_with(&overrides).unwrap();
+
+        assert!(html.contains("<title>Tenant API</title>"));
+        assert!(html.contains(r#""urls.primaryName": "v2""#));
+        assert!(!html.contains("/base.json"));
+        assert_eq!(swagger.serve().unwrap(), base);
+        assert_eq!(swagger.serve_with(&ConfigOverrides::new()).unwrap(), base);
+    }
+
+    #[test]
+    fn serve_with_escapes_title() {
+        let swagger = SwaggerUi::new();
+        let mut overrides = ConfigOverrides::new();
+        overrides.title("</title><script>alert('Q&A')</script>");
+
+        let html = swagger.serve_with(&overrides).unwrap();
+
+        assert!(html.contains(
+            "<title>&lt;/title&gt;&lt;script&gt;alert(&#39;Q&amp;A&#39;)&lt;/script&gt;</title>"
+        ));
+        assert!(!html.contains("<script>alert"));
+    }
+
+    #[test]
+    fn serve_with_invalid_overrides() {
+        let swagger = SwaggerUi::new();
+        let mut overrides = ConfigOverrides::new();
+        overrides.urls([
+            Url::new("v1", "/v1.json").primary(true),
+            Url::new("v2", "/v2.json").primary(true),
+        ]);
+
+        let error = swagger.serve_with(&overrides).unwrap_err();
+
+        assert!(error.is::<InvalidConfigError>());
+    }
+
+    #[test]
+    fn oauth_config_is_initialized() {
+        let mut swagger = SwaggerUi::new();
+        swagger
+            .config()
+            .oauth_config(oauth::Config::new().client_id("base").clone());
+        let mut overrides = ConfigOverrides::new();
+        overrides.oauth_config(oauth::Config::new().client_id("tenant").clone());
+
+        let base = swagger.serve().unwrap();
+        let html = swagger.serve_with(&overrides).unwrap();
+
+        assert!(base.contains("});\nui.initOAuth({\n  \"clientId\": \"base\"\n});"));
+        assert!(html.contains("});\nui.initOAuth({\n  \"clientId\": \"tenant\"\n});"));
+        assert!(!SwaggerUi::new().serve().unwrap().contains("initOAuth"));
+    }
+
+    #[test]
+    fn preauthorizations_after_spec_is_loaded() {
+        let mut swagger = SwaggerUi::new();
+        swagger
+            .config()
+            .preauthorize_basic("basic", "demo", "</script>");
+        let mut overrides = ConfigOverrides::new();
+        overrides.preauthorize_api_key("api_key", "tenant-key");
+
+        let html = swagger.serve_with(&overrides).unwrap();
+
+        assert!(html.contains(
+            r#"  "layout": "StandaloneLayout",
+  onComplete: () => {
+    ui.preauthorizeBasic("basic", "demo", "<\/script>");
+    ui.preauthorizeApiKey("api_key", "tenant-key");
+  },
+  presets: ["#
+        ));
+        assert!(!swagger.serve().unwrap().contains("api_key"));
+    }
+
+    #[test]
+    fn swagger_ui_owned_urls() {
+        let tenants = vec!["acme".to_string(), "globex".to_string()];
+        let mut swagger = SwaggerUi::new();
+        swagger.config().urls(tenants.iter().map(|tenant| {
+            Url::new(format!("{tenant} API"), format!("/{tenant}/openapi.json"))
+                .primary(tenant == "globex")
+        }));
+        drop(tenants);
+
+        let html = swagger.serve().unwrap();
+
+        assert!(html.contains(r#""urls.primaryName": "globex API""#));
+        assert!(html.contains(
+            r#""name": "acme API",
+      "url": "/acme/openapi.json""#
+        ));
+    }
+
+    #[test]
+    fn open_api_gui_set_spec_urls() {
+        let mut swagger = SwaggerUi::new();
+        swagger.set_spec_urls(vec![
+            SpecUrl::new("/api-docs/openapi1.json")
+                .name("api-doc1")
+                .primary(true),
+            SpecUrl::new("/api-docs/openapi2.json").name("api-doc2"),
+        ]);
+
+        let mut expected = Config::new();
+        expected.urls([
+            Url::with_primary("api-doc1", "/api-docs/openapi1.json", true),
+            Url::new("api-doc2", "/api-docs/openapi2.json"),
+        ]);
+        assert_eq!(
+            serde_json::to_value(&swagger.config).unwrap(),
+            serde_json::to_value(&expected).unwrap()
+        );
+    }
+
     #[test]
     fn format_swagger_config_multiple_urls() {
         const EXPECTED: &str = r##"
@@ -1525,6 +3802,8 @@ window.ui = SwaggerUIBundle({
 
         let formatted_config = match format_config(
             Config::new().urls(["/api-docs/openapi1.json", "/api-docs/openapi2.json"]),
+            &ConfigOverrides::new(),
+            ConfigFormat::Pretty,
             TEST_INITIAL_CONFIG,
         ) {
             Ok(file) => file,
@@ -1597,6 +3876,8 @@ window.ui = SwaggerUIBundle({
                 .try_it_out_enabled(true)
                 .validator_url("none")
                 .with_credentials(true),
+            &ConfigOverrides::new(),
+            ConfigFormat::Pretty,
             TEST_INITIAL_CONFIG,
         ) {
             Ok(file) => file,
@@ -1630,6 +3911,8 @@ window.ui = SwaggerUIBundle({
             Config::new()
                 .urls(["/api-docs/openapi1.json"])
                 .with_syntax_highlight(SyntaxHighlight::default()),
+            &ConfigOverrides::new(),
+            ConfigFormat::Pretty,
             TEST_INITIAL_CONFIG,
         ) {
             Ok(file) => file,
@@ -1663,6 +3946,8 @@ window.ui = SwaggerUIBundle({
             Config::new()
                 .urls(["/api-docs/openapi1.json"])
                 .with_syntax_highlight(true),
+            &ConfigOverrides::new(),
+            ConfigFormat::Pretty,
             TEST_INITIAL_CONFIG,
         ) {
             Ok(file) => file,
@@ -1696,6 +3981,8 @@ window.ui = SwaggerUIBundle({
             Config::new()
                 .urls(["/api-docs/openapi1.json"])
                 .with_syntax_highlight(false),
+            &ConfigOverrides::new(),
+            ConfigFormat::Pretty,
             TEST_INITIAL_CONFIG,
         ) {
             Ok(file) => file,
@@ -1730,6 +4017,8 @@ window.ui = SwaggerUIBundle({
             Config::new()
                 .urls(["/api-docs/openapi1.json"])
                 .with_syntax_highlight(SyntaxHighlight::default().theme("monokai")),
+            &ConfigOverrides::new(),
+            ConfigFormat::Pretty,
             TEST_INITIAL_CONFIG,
         ) {
             Ok(file) => file,
@@ -0,0 +1,553 @@
+//! Support for HTTP [range requests](https://www.rfc-editor.org/rfc/rfc9110#section-14).
+//!
+//! Range requests allow clients to download only parts of a file, which is
+//! mostly useful for resuming interrupted downloads of the larger static files
+//! (such as the Swagger UI JavaScript bundle). This module is independent of
+//! any web framework: it takes the raw values of the `Range` and `If-Range`
+//! headers and produces a [`RangeResponse`] containing everything that needs
+//! to be sent back to the client.
+//!
+//! Most users will want to call [`SwaggerFile::range_response`] instead of
+//! using the functions in this module directly.
+//!
+//! # Examples
+//!
+//! ```
+//! # use swagger_ui_redist::SwaggerUi;
+//! let swagger = SwaggerUi::new();
+//! let file = swagger.file("/swagger-ui-bundle.js")?.unwrap();
+//!
+//! let response = file.range_response(Some("bytes=0-99"), None);
+//! assert_eq!(response.status, 206);
+//! assert_eq!(response.body.len(), 100);
+//! # Ok::<(), Box<dyn std::error::Error + Send + Sync>>(())
+//! ```
+//!
+//! [`SwaggerFile::range_response`]: crate::SwaggerFile::range_response
+
+use std::borrow::Cow;
+use std::error::Error;
+use std::fmt::{self, Display, Formatter, Write};
+
+/// The maximum number of ranges accepted in a single `Range` header.
+///
+/// Requests with more ranges are served as if the `Range` header was not
+/// present at all.
+pub const MAX_RANGES: usize = 32;
+
+const BOUNDARY: &str = "swagger-ui-redist-byteranges";
+
+/// A single, satisfiable range of bytes.
+///
+/// Both ends of the range are inclusive, just like in the `Range` and
+/// `Content-Range` headers.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct ByteRange {
+    start: u64,
+    end: u64,
+}
+
+impl ByteRange {
+    /// Returns the position of the first byte in the range.
+    #[must_use]
+    pub fn start(&self) -> u64 {
+        self.start
+    }
+
+    /// Returns the position of the last byte in the range (inclusive).
+    #[must_use]
+    pub fn end(&self) -> u64 {
+        self.end
+    }
+
+    /// Returns the number of bytes in the range.
+    #[must_use]
+    #[expect(clippy::len_without_is_empty, reason = "ranges are never empty")]
+    pub fn len(&self) -> u64 {
+        self.end - self.start + 1
+    }
+
+    /// Returns the value of the `Content-Range` header for this range in a
+    /// file of given length.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use swagger_ui_redist::range::parse_range;
+    /// let ranges = parse_range("bytes=10-19", 100).unwrap();
+    /// assert_eq!(ranges[0].content_range(100), "bytes 10-19/100");
+    /// ```
+    #[must_use]
+    pub fn content_range(&self, complete_length: u64) -> String {
+        format!("bytes {}-{}/{complete_length}", self.start, self.end)
+    }
+
+    fn slice<'a>(&self, bytes: &'a [u8]) -> &'a [u8] {
+        let start = usize::try_from(self.start).expect("range should be within the file");
+        let end = usize::try_from(self.end).expect("range should be within the file");
+
+        &bytes[start..=end]
+    }
+}
+
+/// An error returned by [`parse_range`].
+#[non_exhaustive]
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum RangeError {
+    /// The `Range` header is malformed, uses an unit other than `bytes`, or
+    /// contains too many ranges. Such header should be ignored and the whole
+    /// file should be served.
+    Invalid,
+    /// None of the ranges overlap the file. A `416 Range Not Satisfiable`
+    /// response should be sent.
+    Unsatisfiable,
+}
+
+impl Display for RangeError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            RangeError::Invalid => f.write_str("invalid range header"),
+            RangeError::Unsatisfiable => f.write_str("range not satisfiable"),
+        }
+    }
+}
+
+impl Error for RangeError {}
+
+/// Parses the value of a `Range` header for a file of given length.
+///
+/// Ranges that are not satisfiable are skipped, while the overlapping or
+/// adjacent ones are merged together. The returned ranges are sorted by their
+/// start position and are never empty.
+///
+/// # Errors
+///
+/// Returns [`RangeError::Invalid`] if the header is malformed or contains more
+/// than [`MAX_RANGES`] ranges, and [`RangeError::Unsatisfiable`] if none of the
+/// ranges overlap the file.
+///
+/// # Examples
+///
+/// ```
+/// # use swagger_ui_redist::range::{parse_range, RangeError};
+/// let ranges = parse_range("bytes=0-9, -10", 100)?;
+/// assert_eq!(ranges.len(), 2);
+/// assert_eq!((ranges[0].start(), ranges[0].end()), (0, 9));
+/// assert_eq!((ranges[1].start(), ranges[1].end()), (90, 99));
+///
+/// assert_eq!(
+///     parse_range("bytes=200-", 100),
+///     Err(RangeError::Unsatisfiable)
+/// );
+/// assert_eq!(parse_range("items=0-9", 100), Err(RangeError::Invalid));
+/// # Ok::<(), RangeError>(())
+/// ```
+pub fn parse_range(header: &str, complete_length: u64) -> Result<Vec<ByteRange>, RangeError> {
+    let (unit, range_set) = header.split_once('=').ok_or(RangeError::Invalid)?;
+    if !unit.trim().eq_ignore_ascii_case("bytes") {
+        return Err(RangeError::Invalid);
+    }
+
+    let range_specs: Vec<&str> = range_set
+        .split(',')
+        .map(str::trim)
+        .filter(|range_spec| !range_spec.is_empty())
+        .collect();
+    if range_specs.is_empty() || range_specs.len() > MAX_RANGES {
+        return Err(RangeError::Invalid);
+    }
+
+    let mut ranges = Vec::with_capacity(range_specs.len());
+    for range_spec in range_specs {
+        if let Some(range) = parse_range_spec(range_spec, complete_length)? {
+            ranges.push(range);
+        }
+    }
+    if ranges.is_empty() {
+        return Err(RangeError::Unsatisfiable);
+    }
+
+    ranges.sort_by_key(|range| range.start);
+    let mut merged: Vec<ByteRange> = Vec::with_capacity(ranges.len());
+    for range in ranges {
+        match merged.last_mut() {
+            Some(last) if range.start <= last.end.saturating_add(1) => {
+                last.end = last.end.max(range.end);
+            }
+            _ => merged.push(range),
+        }
+    }
+
+    Ok(merged)
+}
+
+fn parse_range_spec(
+    range_spec: &str,
+    complete_length: u64,
+) -> Result<Option<ByteRange>, RangeError> {
+    let (first, last) = range_spec.split_once('-').ok_or(RangeError::Invalid)?;
+    let (first, last) = (first.trim(), last.trim());
+
+    if first.is_empty() {
+        // suffix range: last N bytes of the file
+        let suffix_length = parse_position(last)?;
+        if suffix_length == 0 || complete_length == 0 {
+            return Ok(None);
+        }
+
+        return Ok(Some(ByteRange {
+            start: complete_length.saturating_sub(suffix_length),
+            end: complete_length - 1,
+        }));
+    }
+
+    let start = parse_position(first)?;
+    let end = if last.is_empty() {
+        u64::MAX
+    } else {
+        parse_position(last)?
+    };
+    if end < start {
+        return Err(RangeError::Invalid);
+    }
+    if start >= complete_length {
+        return Ok(None);
+    }
+
+    Ok(Some(ByteRange {
+        start,
+        end: end.min(complete_length - 1),
+    }))
+}
+
+fn parse_position(position: &str) -> Result<u64, RangeError> {
+    if position.is_empty() || !position.bytes().all(|byte| byte.is_ascii_digit()) {
+        return Err(RangeError::Invalid);
+    }
+
+    position.parse().map_err(|_| RangeError::Invalid)
+}
+
+/// A response to a (possibly) range request.
+///
+/// Besides the fields of this struct, range-aware responses should also
+/// include the `Accept-Ranges: bytes` header and the entity tag of the file
+/// in the `ETag` header, so that the clients know they can resume the
+/// download later.
+#[non_exhaustive]
+#[derive(Debug, Clone)]
+pub struct RangeResponse<'a> {
+    /// HTTP status code of the response: `200 OK`, `206 Partial Content` or
+    /// `416 Range Not Satisfiable`.
+    pub status: u16,
+    /// Value of the `Content-Type` header. For responses containing multiple
+    /// ranges this is `multipart/byteranges` with the boundary parameter.
+    pub content_type: Cow<'a, str>,
+    /// Value of the `Content-Range` header, if it should be sent.
+    pub content_range: Option<String>,
+    /// Body of the response.
+    pub body: Cow<'a, [u8]>,
+}
+
+/// Creates a response for a file, taking the `Range` and `If-Range` request
+/// headers into account.
+///
+/// The `Range` header is ignored (and the whole file is sent with `200 OK`)
+/// if it is malformed, or if the `If-Range` header is present and does not
+/// match the `etag` of the file. Since the files have no modification date,
+/// `If-Range` headers containing a date never match.
+///
+/// # Examples
+///
+/// ```
+/// # use swagger_ui_redist::range::respond;
+/// let bytes = b"Hello, world!";
+///
+/// let response = respond(bytes, "text/plain", "\"etag\"", Some("bytes=7-"), None);
+/// assert_eq!(response.status, 206);
+/// assert_eq!(response.content_range.as_deref(), Some("bytes 7-12/13"));
+/// assert_eq!(&response.body[..], b"world!");
+///
+/// let response = respond(
+///     bytes,
+///     "text/plain",
+///     "\"etag\"",
+///     Some("bytes=7-"),
+///     Some("\"other\""),
+/// );
+/// assert_eq!(response.status, 200);
+/// ```
+#[must_use]
+pub fn respond<'a>(
+    bytes: &'a [u8],
+    content_type: &'a str,
+    etag: &str,
+    range: Option<&str>,
+    if_range: Option<&str>,
+) -> RangeResponse<'a> {
+    let complete_length = bytes.len() as u64;
+    let full_response = || RangeResponse {
+        status: 200,
+        content_type: Cow::Borrowed(content_type),
+        content_range: None,
+        body: Cow::Borrowed(bytes),
+    };
+
+    let Some(range) = range else {
+        return full_response();
+    };
+    if if_range.is_some_and(|if_range| !strong_etag_matches(if_range, etag)) {
+        return full_response();
+    }
+
+    match parse_range(range, complete_length) {
+        Ok(ranges) => match ranges.as_slice() {
+            [range] => RangeResponse {
+                status: 206,
+                content_type: Cow::Borrowed(content_type),
+                content_range: Some(range.content_range(complete_length)),
+                body: Cow::Borrowed(range.slice(bytes)),
+            },
+            ranges => RangeResponse {
+                status: 206,
+                content_type: Cow::Owned(format!("multipart/byteranges; boundary={BOUNDARY}")),
+                content_range: None,
+                body: Cow::Owned(multipart_body(bytes, content_type, ranges)),
+            },
+        },
+        Err(RangeError::Invalid) => full_response(),
+        Err(RangeError::Unsatisfiable) => RangeResponse {
+            status: 416,
+            content_type: Cow::Borrowed(content_type),
+            content_range: Some(format!("bytes */{complete_length}")),
+            body: Cow::Borrowed(&[]),
+        },
+    }
+}
+
+fn strong_etag_matches(if_range: &str, etag: &str) -> bool {
+    let if_range = if_range.trim();
+
+    !if_range.starts_with("W/") && !etag.starts_with("W/") && if_range == etag
+}
+
+fn multipart_body(bytes: &[u8], content_type: &str, ranges: &[ByteRange]) -> Vec<u8> {
+    let complete_length = bytes.len() as u64;
+    let ranges_length: u64 = ranges.iter().map(ByteRange::len).sum();
+    let mut body =
+        Vec::with_capacity(usize::try_from(ranges_length).unwrap_or_default() + ranges.len() * 128);
+
+    for range in ranges {
+        let mut part_header = String::new();
+        write!(
+            part_header,
+            "--{BOUNDARY}\r\nContent-Type: {content_type}\r\nContent-Range: {}\r\n\r\n",
+            range.content_range(complete_length)
+        )
+        .expect("writing to a string should not fail");
+
+        body.extend_from_slice(part_header.as_bytes());
+        body.extend_from_slice(range.slice(bytes));
+        body.extend_from_slice(b"\r\n");
+    }
+    body.extend_from_slice(format!("--{BOUNDARY}--\r\n").as_bytes());
+
+    body
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn range(start: u64, end: u64) -> ByteRange {
+        ByteRange { start, end }
+    }
+
+    #[test]
+    fn parse_single_ranges() {
+        assert_eq!(parse_range("bytes=0-0", 10), Ok(vec![range(0, 0)]));
+        assert_eq!(parse_range("bytes=2-5", 10), Ok(vec![range(2, 5)]));
+        assert_eq!(parse_range("bytes=2-", 10), Ok(vec![range(2, 9)]));
+        assert_eq!(parse_range("bytes=2-100", 10), Ok(vec![range(2, 9)]));
+        assert_eq!(parse_range("bytes=-3", 10), Ok(vec![range(7, 9)]));
+        assert_eq!(parse_range("bytes=-100", 10), Ok(vec![range(0, 9)]));
+        assert_eq!(parse_range("Bytes = 1-2", 10), Ok(vec![range(1, 2)]));
+    }
+
+    #[test]
+    fn parse_multiple_ranges() {
+        assert_eq!(
+            parse_range("bytes=0-1, 5-6", 10),
+            Ok(vec![range(0, 1), range(5, 6)])
+        );
+        assert_eq!(
+            parse_range("bytes=5-6,0-1", 10),
+            Ok(vec![range(0, 1), range(5, 6)])
+        );
+        assert_eq!(
+            parse_range("bytes=0-1, 20-30, 5-6", 10),
+            Ok(vec![range(0, 1), range(5, 6)])
+        );
+    }
+
+    #[test]
+    fn parse_merges_overlapping_ranges() {
+        assert_eq!(parse_range("bytes=0-4,2-6", 10), Ok(vec![range(0, 6)]));
+        assert_eq!(parse_range("bytes=0-4,5-6", 10), Ok(vec![range(0, 6)]));
+        assert_eq!(parse_range("bytes=0-,0-,0-", 10), Ok(vec![range(0, 9)]));
+    }
+
+    #[test]
+    fn parse_invalid() {
+        for header in [
+            "",
+            "bytes",
+            "bytes=",
+            "items=0-1",
+            "bytes=a-b",
+            "bytes=5-1",
+            "bytes=-",
+            "bytes=1",
+            "bytes=+1-2",
+            "bytes=99999999999999999999-",
+        ] {
+            assert_eq!(
+                parse_range(header, 10),
+                Err(RangeError::Invalid),
+                "header: {header}"
+            );
+        }
+    }
+
+    #[test]
+    fn parse_too_many_ranges() {
+        let header = format!("bytes={}", vec!["0-0"; MAX_RANGES + 1].join(","));
+
+        assert_eq!(parse_range(&header, 10), Err(RangeError::Invalid));
+    }
+
+    #[test]
+    fn parse_unsatisfiable() {
+        assert_eq!(parse_range("bytes=10-", 10), Err(RangeError::Unsatisfiable));
+        assert_eq!(parse_range("bytes=-0", 10), Err(RangeError::Unsatisfiable));
+        assert_eq!(parse_range("bytes=0-", 0), Err(RangeError::Unsatisfiable));
+        assert_eq!(parse_range("bytes=-5", 0), Err(RangeError::Unsatisfiable));
+    }
+
+    #[test]
+    fn respond_without_range() {
+        let response = respond(b"0123456789", "text/plain", "\"a\"", None, None);
+
+        assert_eq!(response.status, 200);
+        assert_eq!(response.content_range, None);
+        assert_eq!(&response.body[..], b"0123456789");
+    }
+
+    #[test]
+    fn respond_single_range() {
+        let response = respond(
+            b"0123456789",
+            "text/plain",
+            "\"a\"",
+            Some("bytes=3-5"),
+            None,
+        );
+
+        assert_eq!(response.status, 206);
+        assert_eq!(response.content_type, "text/plain");
+        assert_eq!(response.content_range.as_deref(), Some("bytes 3-5/10"));
+        assert_eq!(&response.body[..], b"345");
+    }
+
+    #[test]
+    fn respond_multiple_ranges() {
+        let response = respond(
+            b"0123456789",
+            "text/plain",
+            "\"a\"",
+            Some("bytes=0-1,-2"),
+            None,
+        );
+
+        assert_eq!(response.status, 206);
+        assert_eq!(
+            response.content_type,
+            "multipart/byteranges; boundary=swagger-ui-redist-byteranges"
+        );
+        assert_eq!(response.content_range, None);
+        assert_eq!(
+            String::from_utf8(response.body.into_owned()).unwrap(),
+            "--swagger-ui-redist-byteranges\r\n\
+             Content-Type: text/plain\r\n\
+             Content-Range: bytes 0-1/10\r\n\
+             \r\n\
+             01\r\n\
+             --swagger-ui-redist-byteranges\r\n\
+             Content-Type: text/plain\r\n\
+             Content-Range: bytes 8-9/10\r\n\
+             \r\n\
+             89\r\n\
+             --swagger-ui-redist-byteranges--\r\n"
+        );
+    }
+
+    #[test]
+    fn respond_unsatisfiable() {
+        let response = respond(
+            b"0123456789",
+            "text/plain",
+            "\"a\"",
+            Some("bytes=10-"),
+            None,
+        );
+
+        assert_eq!(response.status, 416);
+        assert_eq!(response.content_range.as_deref(), Some("bytes */10"));
+        assert!(response.body.is_empty());
+    }
+
+    #[test]
+    fn respond_invalid_range() {
+        let response = respond(b"0123456789", "text/plain", "\"a\"", Some("bytes=x"), None);
+
+        assert_eq!(response.status, 200);
+        assert_eq!(&response.body[..], b"0123456789");
+    }
+
+    #[test]
+    fn respond_if_range() {
+        let matching = respond(
+            b"0123456789",
+            "text/plain",
+            "\"a\"",
+            Some("bytes=0-1"),
+            Some("\"a\""),
+        );
+        let not_matching = respond(
+            b"0123456789",
+            "text/plain",
+            "\"a\"",
+            Some("bytes=0-1"),
+            Some("\"b\""),
+        );
+        let weak = respond(
+            b"0123456789",
+            "text/plain",
+            "\"a\"",
+            Some("bytes=0-1"),
+            Some("W/\"a\""),
+        );
+        let date = respond(
+            b"0123456789",
+            "text/plain",
+            "\"a\"",
+            Some("bytes=0-1"),
+            Some("Wed, 21 Oct 2015 07:28:00 GMT"),
+        );
+
+        assert_eq!(matching.status, 206);
+        assert_eq!(not_matching.status, 200);
+        assert_eq!(weak.status, 200);
+        assert_eq!(date.status, 200);
+    }
+}
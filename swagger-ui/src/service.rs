@@ -0,0 +1,366 @@
+//! [`tower::Service`](tower_service::Service) implementation for serving
+//! Swagger UI.
+//!
+//! This module is only available when the `tower` feature is enabled. It
+//! allows exposing [`SwaggerUi`] in any framework built on top of `tower`, such
+//! as [axum](https://docs.rs/axum) or [tonic](https://docs.rs/tonic), and
+//! putting it behind standard `tower` layers (compression, tracing, auth,
+//! etc.).
+//!
+//! # Examples
+//!
+//! ```
+//! # use swagger_ui_redist::SwaggerUi;
+//! # use swagger_ui_redist::service::SwaggerUiService;
+//! let mut swagger = SwaggerUi::new();
+//! swagger.config().urls(["/api-docs/openapi.json"]);
+//!
+//! let service = SwaggerUiService::new(swagger);
+//! // e.g. with axum: `Router::new().nest_service("/docs", service)`
+//! ```
+
+use std::borrow::Cow;
+use std::convert::Infallible;
+use std::error::Error;
+use std::future::{Ready, ready};
+use std::sync::Arc;
+use std::task::{Context, Poll};
+
+use bytes::Bytes;
+use http::{HeaderValue, Method, Request, Response, StatusCode, header};
+use http_body_util::Full;
+use openapi_gui_core::{INDEX_CONTENT_TYPE, is_index_path};
+use tower_service::Service;
+
+use crate::frozen::FrozenSwaggerUi;
+use crate::{SwaggerFile, SwaggerUi, range};
+
+/// A cloneable [`tower::Service`](tower_service::Service) serving Swagger UI.
+///
+/// The service responds to `GET` and `HEAD` requests with the files returned
+/// by [`SwaggerUi::file`], using the request path as the file path. It is
+/// meant to be mounted under a prefix that the outer router strips before
+/// passing the request on (for example, axum's `nest_service`).
+///
+/// Cloning the service is cheap, as the [`SwaggerUi`] instance is shared
+/// between the clones.
+///
+/// Created from a [`SwaggerUi`], the service renders the page on every
+/// request. To render it only once, create the service from a
+/// [`FrozenSwaggerUi`] instead.
+///
+/// # Examples
+///
+/// ```
+/// # use swagger_ui_redist::SwaggerUi;
+/// # use swagger_ui_redist::service::SwaggerUiService;
+/// let service = SwaggerUiService::new(SwaggerUi::new());
+/// let cloned = service.clone();
+///
+/// let frozen = SwaggerUiService::from(SwaggerUi::new().freeze()?);
+/// # Ok::<(), Box<dyn std::error::Error + Send + Sync>>(())
+/// ```
+#[derive(Debug, Clone)]
+pub struct SwaggerUiService {
+    gui: Gui,
+}
+
+#[derive(Debug, Clone)]
+enum Gui {
+    Live(Arc<SwaggerUi>),
+    Frozen(FrozenSwaggerUi),
+}
+
+impl SwaggerUiService {
+    /// Creates a new [`SwaggerUiService`] serving given [`SwaggerUi`].
+    #[must_use]
+    pub fn new(swagger_ui: SwaggerUi) -> Self {
+        Self {
+            gui: Gui::Live(Arc::new(swagger_ui)),
+        }
+    }
+
+    /// Looks up the file to respond with, sharing its content rather than
+    /// copying it where possible.
+    fn file(&self, path: &str) -> Result<Option<ServedFile>, Box<dyn Error + Send + Sync>> {
+        Ok(match &self.gui {
+            Gui::Live(swagger_ui) => swagger_ui.file(path)?.map(ServedFile::from),
+            Gui::Frozen(frozen) if is_index_path(path) => Some(ServedFile {
+                bytes: frozen.index_bytes(),
+                content_type: INDEX_CONTENT_TYPE.to_string(),
+                etag: frozen.etag().to_string(),
+            }),
+            Gui::Frozen(frozen) => frozen.swagger_ui().asset_file(path).map(ServedFile::from),
+        })
+    }
+
+    fn respond<B>(&self, request: &Request<B>) -> Response<Full<Bytes>> {
+        let method = request.method();
+        if method != Method::GET && method != Method::HEAD {
+            let mut response = empty_response(StatusCode::METHOD_NOT_ALLOWED);
+            response
+                .headers_mut()
+                .insert(header::ALLOW, HeaderValue::from_static("GET, HEAD"));
+            return response;
+        }
+
+        let file = match self.file(request.uri().path()) {
+            Ok(Some(file)) => file,
+            Ok(None) => return empty_response(StatusCode::NOT_FOUND),
+            Err(_) => return empty_response(StatusCode::INTERNAL_SERVER_ERROR),
+        };
+
+        // Range requests are only defined for GET
+        let (range, if_range) = if method == Method::GET {
+            (
+                header_str(request, header::RANGE),
+                header_str(request, header::IF_RANGE),
+            )
+        } else {
+            (None, None)
+        };
+        let bytes = file.bytes;
+        let range_response =
+            range::respond(&bytes, &file.content_type, &file.etag, range, if_range);
+
+        let status = StatusCode::from_u16(range_response.status)
+            .expect("range response status should be valid");
+        let body = if method == Method::HEAD {
+            Bytes::new()
+        } else {
+            match range_response.body {
+                Cow::Borrowed(body) => bytes.slice_ref(body),
+                Cow::Owned(body) => Bytes::from(body),
+            }
+        };
+
+        let mut response = Response::new(Full::new(body));
+        *response.status_mut() = status;
+        let headers = response.headers_mut();
+        headers.insert(
+            header::CONTENT_TYPE,
+            HeaderValue::from_str(&range_response.content_type)
+                .expect("content type should be a valid header value"),
+        );
+        headers.insert(header::ACCEPT_RANGES, HeaderValue::from_static("bytes"));
+        headers.insert(
+            header::ETAG,
+            HeaderValue::from_str(&file.etag).expect("etag should be a valid header value"),
+        );
+        if let Some(content_range) = range_response.content_range {
+            headers.insert(
+                header::CONTENT_RANGE,
+                HeaderValue::from_str(&content_range)
+                    .expect("content range should be a valid header value"),
+            );
+        }
+        response
+    }
+}
+
+impl From<SwaggerUi> for SwaggerUiService {
+    fn from(swagger_ui: SwaggerUi) -> Self {
+        Self::new(swagger_ui)
+    }
+}
+
+impl From<FrozenSwaggerUi> for SwaggerUiService {
+    fn from(frozen: FrozenSwaggerUi) -> Self {
+        Self {
+            gui: Gui::Frozen(frozen),
+        }
+    }
+}
+
+impl<B> Service<Request<B>> for SwaggerUiService {
+    type Response = Response<Full<Bytes>>;
+    type Error = Infallible;
+    type Future = Ready<Result<Self::Response, Self::Error>>;
+
+    fn poll_ready(&mut self, _cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        Poll::Ready(Ok(()))
+    }
+
+    fn call(&mut self, request: Request<B>) -> Self::Future {
+        ready(Ok(self.respond(&request)))
+    }
+}
+
+fn header_str<B>(request: &Request<B>, name: header::HeaderName) -> Option<&str> {
+    request
+        .headers()
+        .get(name)
+        .and_then(|value| value.to_str().ok())
+}
+
+/// A file to respond with.
+struct ServedFile {
+    bytes: Bytes,
+    content_type: String,
+    etag: String,
+}
+
+impl From<SwaggerFile<'static>> for ServedFile {
+    fn from(file: SwaggerFile<'static>) -> Self {
+        Self {
+            bytes: match file.bytes {
+                Cow::Borrowed(bytes) => Bytes::from_static(bytes),
+                Cow::Owned(bytes) => Bytes::from(bytes),
+            },
+            content_type: file.content_type,
+            etag: file.etag,
+        }
+    }
+}
+
+fn empty_response(status: StatusCode) -> Response<Full<Bytes>> {
+    let mut response = Response::new(Full::new(Bytes::new()));
+    *response.status_mut() = status;
+    response
+}
+
+#[cfg(test)]
+mod tests {
+    use std::pin::pin;
+    use std::task::Waker;
+
+    use http_body::Body;
+
+    use super::*;
+    use crate::SwaggerUiStaticFile;
+
+    fn call(service: &mut SwaggerUiService, method: Method, path: &str) -> Response<Full<Bytes>> {
+        let request = Request::builder()
+            .method(method)
+            .uri(path)
+            .body(())
+            .unwrap();
+        service.call(request).into_inner().unwrap()
+    }
+
+    fn body_bytes(response: Response<Full<Bytes>>) -> Bytes {
+        let mut body = pin!(response.into_body());
+        let mut cx = Context::from_waker(Waker::noop());
+
+        match body.as_mut().poll_frame(&mut cx) {
+            Poll::Ready(Some(Ok(frame))) => frame.into_data().unwrap(),
+            Poll::Ready(None) => Bytes::new(),
+            Poll::Ready(Some(Err(error))) => match error {},
+            Poll::Pending => unreachable!("full body should always be ready"),
+        }
+    }
+
+    fn service() -> SwaggerUiService {
+        let mut swagger = SwaggerUi::new();
+        swagger.config().urls(["/api-docs/openapi.json"]);
+        SwaggerUiService::new(swagger)
+    }
+
+    #[test]
+    fn serves_index() {
+        let response = call(&mut service(), Method::GET, "/");
+
+        assert_eq!(response.status(), StatusCode::OK);
+        assert_eq!(
+            response.headers()[header::CONTENT_TYPE],
+            "text/html; charset=utf-8"
+        );
+    }
+
+    #[test]
+    fn serves_frozen_index() {
+        let mut swagger = SwaggerUi::new();
+        swagger.config().urls(["/api-docs/openapi.json"]);
+        let frozen = swagger.freeze().unwrap();
+        let mut service = SwaggerUiService::from(frozen.clone());
+
+        let response = call(&mut service, Method::GET, "/");
+        assert_eq!(response.status(), StatusCode::OK);
+        assert_eq!(response.headers()[header::ETAG], frozen.etag());
+        assert_eq!(body_bytes(response), frozen.index().as_bytes());
+
+        let response = call(&mut service, Method::GET, "/swagger-ui.css");
+        assert_eq!(body_bytes(response), SwaggerUiStaticFile::Css.bytes());
+    }
+
+    #[test]
+    fn serves_static_files() {
+        let mut service = service();
+
+        for static_file in SwaggerUiStaticFile::all() {
+            let response = call(
+                &mut service,
+                Method::GET,
+                &format!("/{}", static_file.file_name()),
+            );
+
+            assert_eq!(response.status(), StatusCode::OK);
+            assert_eq!(
+                response.headers()[header::CONTENT_TYPE],
+                static_file.content_type()
+            );
+            assert_eq!(body_bytes(response), static_file.bytes());
+        }
+    }
+
+    #[test]
+    fn head_has_empty_body() {
+        let response = call(&mut service(), Method::HEAD, "/swagger-ui.css");
+
+        assert_eq!(response.status(), StatusCode::OK);
+        assert!(body_bytes(response).is_empty());
+    }
+
+    #[test]
+    fn serves_ranges() {
+        let request = Request::builder()
+            .uri("/swagger-ui-bundle.js")
+            .header(header::RANGE, "bytes=0-9")
+            .body(())
+            .unwrap();
+        let response = service().call(request).into_inner().unwrap();
+
+        assert_eq!(response.status(), StatusCode::PARTIAL_CONTENT);
+        assert_eq!(response.headers()[header::ACCEPT_RANGES], "bytes");
+        assert_eq!(
+            response.headers()[header::ETAG],
+            SwaggerUiStaticFile::Js.etag()
+        );
+        assert_eq!(
+            response.headers()[header::CONTENT_RANGE],
+            format!("bytes 0-9/{}", SwaggerUiStaticFile::Js.bytes().len())
+        );
+        assert_eq!(body_bytes(response), SwaggerUiStaticFile::Js.bytes()[..10]);
+    }
+
+    #[test]
+    fn unsatisfiable_range() {
+        let request = Request::builder()
+            .uri("/favicon-16x16.png")
+            .header(header::RANGE, "bytes=100000-")
+            .body(())
+            .unwrap();
+        let response = service().call(request).into_inner().unwrap();
+
+        assert_eq!(response.status(), StatusCode::RANGE_NOT_SATISFIABLE);
+        assert_eq!(
+            response.headers()[header::CONTENT_RANGE],
+            format!("bytes */{}", SwaggerUiStaticFile::Favicon16.bytes().len())
+        );
+    }
+
+    #[test]
+    fn not_found() {
+        let response = call(&mut service(), Method::GET, "/missing.js");
+
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+    }
+
+    #[test]
+    fn method_not_allowed() {
+        let response = call(&mut service(), Method::POST, "/");
+
+        assert_eq!(response.status(), StatusCode::METHOD_NOT_ALLOWED);
+        assert_eq!(response.headers()[header::ALLOW], "GET, HEAD");
+    }
+}
@@ -0,0 +1,227 @@
+//! HTML template used to render the Swagger UI page.
+//!
+//! The template is a plain string containing named slots in the form of
+//! `{{slot}}`, which are replaced with the actual content when the page is
+//! rendered. The following slots are available:
+//!
+//! * `{{lang}}` - the language of the page, set with [`SwaggerUi::lang`].
+//! * `{{title}}` - the HTML-escaped title of the page, set with
+//!   [`SwaggerUi::title`].
+//! * `{{css}}` and `{{index_css}}` - the tags including the Swagger UI
+//!   stylesheets.
+//! * `{{favicon_32}}` and `{{favicon_16}}` - the URLs of the favicons, and
+//!   `{{favicon_type}}` - their content type. Both URLs point to the same image
+//!   if a custom favicon is set with [`SwaggerUi::favicon`].
+//! * `{{js}}` and `{{standalone_preset_js}}` - the tags including the Swagger
+//!   UI scripts.
+//! * `{{custom_css}}` and `{{custom_js}}` - the tags including the optional
+//!   stylesheets and scripts, one per line: the dark theme enabled with
+//!   [`SwaggerUi::theme`], the translation set with [`SwaggerUi::translation`],
+//!   the branding set with [`SwaggerUi::logo`] and [`SwaggerUi::logo_link`],
+//!   followed by the custom ones added with [`SwaggerUi::add_stylesheet`] and
+//!   [`SwaggerUi::add_script`].
+//! * `{{config}}` - the script initializing Swagger UI with the [`Config`].
+//! * `{{head}}`, `{{body_start}}` and `{{body_end}}` - custom HTML set with
+//!   [`Template::head`], [`Template::body_start`] and [`Template::body_end`].
+//!
+//! Slots that are not listed above are left in the page untouched.
+//!
+//! [`SwaggerUi::lang`]: crate::SwaggerUi::lang
+//! [`SwaggerUi::title`]: crate::SwaggerUi::title
+//! [`SwaggerUi::favicon`]: crate::SwaggerUi::favicon
+//! [`SwaggerUi::theme`]: crate::SwaggerUi::theme
+//! [`SwaggerUi::translation`]: crate::SwaggerUi::translation
+//! [`SwaggerUi::logo`]: crate::SwaggerUi::logo
+//! [`SwaggerUi::logo_link`]: crate::SwaggerUi::logo_link
+//! [`SwaggerUi::add_stylesheet`]: crate::SwaggerUi::add_stylesheet
+//! [`SwaggerUi::add_script`]: crate::SwaggerUi::add_script
+//! [`Config`]: crate::Config
+
+use std::borrow::Cow;
+
+/// The template used by default, rendering the same page as the upstream
+/// Swagger UI distribution.
+pub const DEFAULT_TEMPLATE: &str = r#"<!DOCTYPE html>
+<html lang="{{lang}}">
+<head>
+    <meta charset="UTF-8">
+    <title>{{title}}</title>
+    {{css}}
+    {{index_css}}
+{{custom_css}}    <link rel="icon" type="{{favicon_type}}" href="{{favicon_32}}" sizes="32x32" />
+    <link rel="icon" type="{{favicon_type}}" href="{{favicon_16}}" sizes="16x16" />
+{{head}}</head>
+<body>
+{{body_start}}<div id="swagger-ui"></div>
+{{js}}
+{{standalone_preset_js}}
+{{custom_js}}<script>
+    window.onload = () => {
+        {{config}}
+    };
+</script>
+{{body_end}}</body>
+</html>
+"#;
+
+/// HTML template of the Swagger UI page.
+///
+/// The default template can be extended with custom HTML inserted at the end
+/// of `<head>`, and at the start or the end of `<body>`, which is enough to
+/// add a header banner, a footer, or an analytics script. For more control,
+/// the whole template can be replaced with [`Template::custom`]; see the
+/// [module documentation](self) for the list of slots.
+///
+/// # Examples
+///
+/// ```
+/// # use swagger_ui_redist::SwaggerUi;
+/// let mut swagger = SwaggerUi::new();
+/// swagger
+///     .template()
+///     .head(r#"<meta name="robots" content="noindex">"#)
+///     .body_end("<footer>© Example Corp</footer>");
+///
+/// let html = swagger.serve()?;
+/// assert!(html.contains("<footer>© Example Corp</footer>\n</body>"));
+/// # Ok::<(), Box<dyn std::error::Error + Send + Sync>>(())
+/// ```
+#[non_exhaustive]
+#[derive(Debug, Clone)]
+pub struct Template {
+    source: Cow<'static, str>,
+    head: String,
+    body_start: String,
+    body_end: String,
+}
+
+impl Default for Template {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Template {
+    /// Creates a new [`Template`] using [`DEFAULT_TEMPLATE`].
+    #[must_use]
+    pub fn new() -> Self {
+        Self::custom(DEFAULT_TEMPLATE)
+    }
+
+    /// Creates a new [`Template`] from given source containing `{{slot}}`
+    /// placeholders.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use swagger_ui_redist::template::Template;
+    /// let template = Template::custom(
+    ///     r#"<!DOCTYPE html>
+    /// <html>
+    /// <head><title>{{title}}</title>{{css}}{{head}}</head>
+    /// <body>
+    /// <div id="swagger-ui"></div>
+    /// {{js}}{{standalone_preset_js}}
+    /// <script>window.onload = () => { {{config}} };</script>
+    /// </body>
+    /// </html>"#,
+    /// );
+    /// ```
+    #[must_use]
+    pub fn custom(source: impl Into<Cow<'static, str>>) -> Self {
+        Self {
+            source: source.into(),
+            head: String::new(),
+            body_start: String::new(),
+            body_end: String::new(),
+        }
+    }
+
+    /// Sets the HTML inserted in place of the `{{head}}` slot, which is right
+    /// before `</head>` in the default template.
+    pub fn head(&mut self, html: impl Into<String>) -> &mut Self {
+        self.head = line(html.into());
+        self
+    }
+
+    /// Sets the HTML inserted in place of the `{{body_start}}` slot, which is
+    /// right after `<body>` in the default template.
+    pub fn body_start(&mut self, html: impl Into<String>) -> &mut Self {
+        self.body_start = line(html.into());
+        self
+    }
+
+    /// Sets the HTML inserted in place of the `{{body_end}}` slot, which is
+    /// right before `</body>` in the default template.
+    pub fn body_end(&mut self, html: impl Into<String>) -> &mut Self {
+        self.body_end = line(html.into());
+        self
+    }
+
+    /// Renders the template, replacing the slots with the content returned by
+    /// `slot`, or the custom HTML set for the template.
+    ///
+    /// The template is scanned only once, so the slot content is never
+    /// interpreted as a template itself.
+    pub(crate) fn render<'a>(&'a self, slot: impl Fn(&str) -> Option<&'a str>) -> String {
+        let mut output = String::with_capacity(self.source.len());
+        let mut rest = &*self.source;
+
+        while let Some(start) = rest.find("{{") {
+            let Some(end) = rest[start..].find("}}").map(|end| start + end) else {
+                break;
+            };
+            let name = &rest[start + 2..end];
+            let content = match name {
+                "head" => Some(self.head.as_str()),
+                "body_start" => Some(self.body_start.as_str()),
+                "body_end" => Some(self.body_end.as_str()),
+                _ => slot(name),
+            };
+
+            output.push_str(&rest[..start]);
+            match content {
+                Some(content) => output.push_str(content),
+                None => output.push_str(&rest[start..end + 2]),
+            }
+            rest = &rest[end + 2..];
+        }
+        output.push_str(rest);
+
+        output
+    }
+}
+
+/// Makes sure non-empty custom HTML ends with a newline, so it's placed on its
+/// own line(s) in the rendered page.
+fn line(mut html: String) -> String {
+    if !html.is_empty() && !html.ends_with('\n') {
+        html.push('\n');
+    }
+    html
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn render_replaces_slots_once() {
+        let mut template = Template::custom("<p>{{a}}</p>{{head}}{{unknown}}{{");
+        template.head("<meta>");
+
+        let html = template.render(|name| (name == "a").then_some("{{head}}"));
+
+        assert_eq!(html, "<p>{{head}}</p><meta>\n{{unknown}}{{");
+    }
+
+    #[test]
+    fn default_template_without_custom_html() {
+        let template = Template::new();
+
+        let html = template.render(|_| Some(""));
+
+        assert!(html.contains("\n</head>\n<body>\n<div id=\"swagger-ui\"></div>\n"));
+        assert!(html.ends_with("</script>\n</body>\n</html>\n"));
+    }
+}
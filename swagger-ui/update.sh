@@ -9,13 +9,23 @@ if [ -z "$1" ]; then
 fi
 
 VERSION="$1"
+MAJOR="${VERSION#v}"
+MAJOR="${MAJOR%%.*}"
+DIR="res/v$MAJOR"
 
-curl -o res/LICENSE https://raw.githubusercontent.com/swagger-api/swagger-ui/refs/tags/$VERSION/LICENSE
-curl -o res/swagger-ui.css https://raw.githubusercontent.com/swagger-api/swagger-ui/refs/tags/$VERSION/dist/swagger-ui.css
-curl -o res/index.css https://raw.githubusercontent.com/swagger-api/swagger-ui/refs/tags/$VERSION/dist/index.css
-curl -o res/swagger-ui-bundle.js https://raw.githubusercontent.com/swagger-api/swagger-ui/refs/tags/$VERSION/dist/swagger-ui-bundle.js
-curl -o res/swagger-ui-standalone-preset.js https://raw.githubusercontent.com/swagger-api/swagger-ui/refs/tags/$VERSION/dist/swagger-ui-standalone-preset.js
-curl -o res/favicon-16x16.png https://raw.githubusercontent.com/swagger-api/swagger-ui/refs/tags/$VERSION/dist/favicon-16x16.png
-curl -o res/favicon-32x32.png https://raw.githubusercontent.com/swagger-api/swagger-ui/refs/tags/$VERSION/dist/favicon-32x32.png
+if [ ! -d "$DIR" ]; then
+    echo "Unsupported Swagger UI major version: $MAJOR"
+    exit 1
+fi
+
+curl -o $DIR/LICENSE https://raw.githubusercontent.com/swagger-api/swagger-ui/refs/tags/$VERSION/LICENSE
+curl -o $DIR/swagger-ui.css https://raw.githubusercontent.com/swagger-api/swagger-ui/refs/tags/$VERSION/dist/swagger-ui.css
+curl -o $DIR/index.css https://raw.githubusercontent.com/swagger-api/swagger-ui/refs/tags/$VERSION/dist/index.css
+curl -o $DIR/swagger-ui-bundle.js https://raw.githubusercontent.com/swagger-api/swagger-ui/refs/tags/$VERSION/dist/swagger-ui-bundle.js
+curl -o $DIR/swagger-ui-standalone-preset.js https://raw.githubusercontent.com/swagger-api/swagger-ui/refs/tags/$VERSION/dist/swagger-ui-standalone-preset.js
+curl -o $DIR/favicon-16x16.png https://raw.githubusercontent.com/swagger-api/swagger-ui/refs/tags/$VERSION/dist/favicon-16x16.png
+curl -o $DIR/favicon-32x32.png https://raw.githubusercontent.com/swagger-api/swagger-ui/refs/tags/$VERSION/dist/favicon-32x32.png
+curl -o $DIR/oauth2-redirect.html https://raw.githubusercontent.com/swagger-api/swagger-ui/refs/tags/$VERSION/dist/oauth2-redirect.html
+printf '%s' "${VERSION#v}" > $DIR/VERSION
 
-sed -i "s/<!-- version -->.*$/<!-- version -->The version of Swagger UI included in this crate is $VERSION./" README.md
+sed -i "s/^\* \`v$MAJOR\` feature: .*$/* \`v$MAJOR\` feature: Swagger UI $VERSION/" README.md